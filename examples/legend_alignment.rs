@@ -74,30 +74,27 @@ fn run(mut terminal: DefaultTerminal, app: &mut App) -> Result<()> {
         if let Event::Key(key) = event::read()? {
             match key.code {
                 KeyCode::Char('q') | KeyCode::Esc => break,
-                KeyCode::Up | KeyCode::Char('k') => {
-                    if app.alignment_index > 0 {
-                        app.alignment_index -= 1;
-                    }
+                KeyCode::Up | KeyCode::Char('k') if app.alignment_index > 0 => {
+                    app.alignment_index -= 1;
                 }
-                KeyCode::Down | KeyCode::Char('j') => {
-                    if app.alignment_index < app.alignments.len() - 1 {
-                        app.alignment_index += 1;
-                    }
+                KeyCode::Down | KeyCode::Char('j')
+                    if app.alignment_index < app.alignments.len() - 1 =>
+                {
+                    app.alignment_index += 1;
                 }
-                KeyCode::Left | KeyCode::Char('h') => {
-                    if app.position_index > 0 {
-                        app.position_index -= 1;
-                    }
+                KeyCode::Left | KeyCode::Char('h') if app.position_index > 0 => {
+                    app.position_index -= 1;
                 }
-                KeyCode::Right | KeyCode::Char('l') => {
-                    if app.position_index < app.positions.len() - 1 {
-                        app.position_index += 1;
-                    }
+                KeyCode::Right | KeyCode::Char('l')
+                    if app.position_index < app.positions.len() - 1 =>
+                {
+                    app.position_index += 1;
                 }
                 KeyCode::Char(' ') => {
                     app.layout = match app.layout {
                         LegendLayout::Vertical => LegendLayout::Horizontal,
-                        LegendLayout::Horizontal => LegendLayout::Vertical,
+                        LegendLayout::Horizontal => LegendLayout::Table,
+                        LegendLayout::Table => LegendLayout::Vertical,
                     };
                 }
                 _ => {}
@@ -172,6 +169,7 @@ fn render_main_chart(frame: &mut Frame, area: Rect, app: &App) {
     let layout_name = match app.layout {
         LegendLayout::Vertical => "Vertical",
         LegendLayout::Horizontal => "Horizontal",
+        LegendLayout::Table => "Table",
     };
 
     let slices = vec![
@@ -179,15 +179,13 @@ fn run(mut terminal: DefaultTerminal, app: &mut App) -> Result<()> {
         if let Event::Key(key) = event::read()? {
             match key.code {
                 KeyCode::Char('q') | KeyCode::Esc => break,
-                KeyCode::Up | KeyCode::Char('k') => {
-                    if app.marker_index > 0 {
-                        app.marker_index -= 1;
-                    }
+                KeyCode::Up | KeyCode::Char('k') if app.marker_index > 0 => {
+                    app.marker_index -= 1;
                 }
-                KeyCode::Down | KeyCode::Char('j') => {
-                    if app.marker_index < MARKER_STYLES.len() - 1 {
-                        app.marker_index += 1;
-                    }
+                KeyCode::Down | KeyCode::Char('j')
+                    if app.marker_index < MARKER_STYLES.len() - 1 =>
+                {
+                    app.marker_index += 1;
                 }
                 _ => {}
             }
@@ -43,6 +43,11 @@ impl Default for App {
                 (LegendPosition::Left, "Left"),
                 (LegendPosition::Top, "Top"),
                 (LegendPosition::Bottom, "Bottom"),
+                (LegendPosition::TopLeft, "Top Left (overlay)"),
+                (LegendPosition::TopRight, "Top Right (overlay)"),
+                (LegendPosition::BottomLeft, "Bottom Left (overlay)"),
+                (LegendPosition::BottomRight, "Bottom Right (overlay)"),
+                (LegendPosition::Auto, "Auto"),
             ],
         }
     }
@@ -64,20 +69,19 @@ fn run(mut terminal: DefaultTerminal, app: &mut App) -> Result<()> {
         if let Event::Key(key) = event::read()? {
             match key.code {
                 KeyCode::Char('q') | KeyCode::Esc => break,
-                KeyCode::Up | KeyCode::Char('k') => {
-                    if app.position_index > 0 {
-                        app.position_index -= 1;
-                    }
+                KeyCode::Up | KeyCode::Char('k') if app.position_index > 0 => {
+                    app.position_index -= 1;
                 }
-                KeyCode::Down | KeyCode::Char('j') => {
-                    if app.position_index < app.positions.len() - 1 {
-                        app.position_index += 1;
-                    }
+                KeyCode::Down | KeyCode::Char('j')
+                    if app.position_index < app.positions.len() - 1 =>
+                {
+                    app.position_index += 1;
                 }
                 KeyCode::Left | KeyCode::Char('h') | KeyCode::Right | KeyCode::Char('l') => {
                     app.layout = match app.layout {
                         LegendLayout::Vertical => LegendLayout::Horizontal,
-                        LegendLayout::Horizontal => LegendLayout::Vertical,
+                        LegendLayout::Horizontal => LegendLayout::Table,
+                        LegendLayout::Table => LegendLayout::Vertical,
                     };
                 }
                 _ => {}
@@ -152,6 +156,7 @@ fn render_main_chart(frame: &mut Frame, area: Rect, app: &App) {
     let layout_name = match app.layout {
         LegendLayout::Vertical => "Vertical",
         LegendLayout::Horizontal => "Horizontal",
+        LegendLayout::Table => "Table",
     };
 
     let slices = vec![
@@ -64,15 +64,11 @@ fn run(mut terminal: DefaultTerminal, app: &mut App) -> Result<()> {
             if let Event::Key(key) = event::read()? {
                 match key.code {
                     KeyCode::Char('q') | KeyCode::Esc => break,
-                    KeyCode::Up | KeyCode::Char('k') => {
-                        if app.selected > 0 {
-                            app.selected -= 1;
-                        }
+                    KeyCode::Up | KeyCode::Char('k') if app.selected > 0 => {
+                        app.selected -= 1;
                     }
-                    KeyCode::Down | KeyCode::Char('j') => {
-                        if app.selected < 3 {
-                            app.selected += 1;
-                        }
+                    KeyCode::Down | KeyCode::Char('j') if app.selected < 3 => {
+                        app.selected += 1;
                     }
                     _ => {}
                 }
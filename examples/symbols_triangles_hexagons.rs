@@ -39,25 +39,19 @@ fn run(mut terminal: DefaultTerminal, app: &mut App) -> Result<()> {
         if let Event::Key(key) = event::read()? {
             match key.code {
                 KeyCode::Char('q') | KeyCode::Esc => break,
-                KeyCode::Up | KeyCode::Char('k') => {
-                    if app.selected >= 2 {
-                        app.selected -= 2;
-                    }
+                KeyCode::Up | KeyCode::Char('k') if app.selected >= 2 => {
+                    app.selected -= 2;
                 }
-                KeyCode::Down | KeyCode::Char('j') => {
-                    if app.selected < 2 {
-                        app.selected += 2;
-                    }
+                KeyCode::Down | KeyCode::Char('j') if app.selected < 2 => {
+                    app.selected += 2;
                 }
-                KeyCode::Left | KeyCode::Char('h') => {
-                    if app.selected % 2 == 1 {
-                        app.selected -= 1;
-                    }
+                KeyCode::Left | KeyCode::Char('h') if app.selected % 2 == 1 => {
+                    app.selected -= 1;
                 }
-                KeyCode::Right | KeyCode::Char('l') => {
-                    if app.selected % 2 == 0 && app.selected < 3 {
-                        app.selected += 1;
-                    }
+                KeyCode::Right | KeyCode::Char('l')
+                    if app.selected % 2 == 0 && app.selected < 3 =>
+                {
+                    app.selected += 1;
                 }
                 _ => {}
             }
@@ -138,35 +138,30 @@ fn run(mut terminal: DefaultTerminal, app: &mut App) -> Result<()> {
                 KeyCode::Char(' ') | KeyCode::Tab => {
                     app.selected_position = (app.selected_position + 1) % app.positions.len();
                 }
-                KeyCode::Up | KeyCode::Char('k') => {
-                    if app.mode == DemoMode::Styles && app.selected_style > 0 {
-                        app.selected_style -= 1;
-                    }
+                KeyCode::Up | KeyCode::Char('k')
+                    if app.mode == DemoMode::Styles && app.selected_style > 0 =>
+                {
+                    app.selected_style -= 1;
                 }
-                KeyCode::Down | KeyCode::Char('j') => {
-                    if app.mode == DemoMode::Styles && app.selected_style < app.styles.len() - 1 {
-                        app.selected_style += 1;
-                    }
+                KeyCode::Down | KeyCode::Char('j')
+                    if app.mode == DemoMode::Styles
+                        && app.selected_style < app.styles.len() - 1 =>
+                {
+                    app.selected_style += 1;
                 }
-                KeyCode::Left | KeyCode::Char('h') => {
-                    if app.selected_alignment > 0 {
-                        app.selected_alignment -= 1;
-                    }
+                KeyCode::Left | KeyCode::Char('h') if app.selected_alignment > 0 => {
+                    app.selected_alignment -= 1;
                 }
-                KeyCode::Right | KeyCode::Char('l') => {
-                    if app.selected_alignment < app.alignments.len() - 1 {
-                        app.selected_alignment += 1;
-                    }
+                KeyCode::Right | KeyCode::Char('l')
+                    if app.selected_alignment < app.alignments.len() - 1 =>
+                {
+                    app.selected_alignment += 1;
                 }
-                KeyCode::Home => {
-                    if app.mode == DemoMode::Styles {
-                        app.selected_style = 0;
-                    }
+                KeyCode::Home if app.mode == DemoMode::Styles => {
+                    app.selected_style = 0;
                 }
-                KeyCode::End => {
-                    if app.mode == DemoMode::Styles {
-                        app.selected_style = app.styles.len() - 1;
-                    }
+                KeyCode::End if app.mode == DemoMode::Styles => {
+                    app.selected_style = app.styles.len() - 1;
                 }
                 _ => {}
             }
@@ -102,15 +102,11 @@ fn run(mut terminal: DefaultTerminal, app: &mut App) -> Result<()> {
         if let Event::Key(key) = event::read()? {
             match key.code {
                 KeyCode::Char('q') | KeyCode::Esc => break,
-                KeyCode::Up | KeyCode::Char('k') => {
-                    if app.selected > 0 {
-                        app.selected -= 1;
-                    }
+                KeyCode::Up | KeyCode::Char('k') if app.selected > 0 => {
+                    app.selected -= 1;
                 }
-                KeyCode::Down | KeyCode::Char('j') => {
-                    if app.selected < app.styles.len() - 1 {
-                        app.selected += 1;
-                    }
+                KeyCode::Down | KeyCode::Char('j') if app.selected < app.styles.len() - 1 => {
+                    app.selected += 1;
                 }
                 KeyCode::Home => {
                     app.selected = 0;
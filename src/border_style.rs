@@ -53,7 +53,26 @@ pub use crate::title::{BlockExt, TitleAlignment, TitlePosition};
 /// let chart = PieChart::new(slices)
 ///     .block(BorderStyle::ThickRounded.block().title("Bold"));
 /// ```
+///
+/// # Parsing
+///
+/// Every variant has a kebab-case name used by `Display` and `FromStr`.
+/// Parsing ignores case.
+///
+/// ```
+/// use tui_piechart::border_style::BorderStyle;
+///
+/// let style: BorderStyle = "rounded-dashed".parse().unwrap();
+/// assert_eq!(style, BorderStyle::RoundedDashed);
+/// assert_eq!(style.to_string(), "rounded-dashed");
+/// assert_eq!(BorderStyle::iter().count(), BorderStyle::ALL.len());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum BorderStyle {
     /// Standard single-line borders (default)
     #[default]
@@ -80,6 +99,26 @@ pub enum BorderStyle {
     ThickDashed,
     /// Thick borders with gaps only at corners
     ThickCornerGapped,
+    /// Plain ASCII borders (`+`, `-`, `|`) for terminals without box-drawing glyphs
+    Ascii,
+}
+
+crate::macros::option::option_enum! {
+    BorderStyle {
+        Standard => "standard",
+        Rounded => "rounded",
+        Dashed => "dashed",
+        RoundedDashed => "rounded-dashed",
+        CornerGapped => "corner-gapped",
+        RoundedCornerGapped => "rounded-corner-gapped",
+        DoubleLineStandard => "double-line-standard",
+        DoubleLineRounded => "double-line-rounded",
+        Thick => "thick",
+        ThickRounded => "thick-rounded",
+        ThickDashed => "thick-dashed",
+        ThickCornerGapped => "thick-corner-gapped",
+        Ascii => "ascii",
+    }
 }
 
 impl BorderStyle {
@@ -107,6 +146,7 @@ impl BorderStyle {
             Self::ThickRounded => Block::bordered().border_set(BORDER_THICK_ROUNDED),
             Self::ThickDashed => Block::bordered().border_set(BORDER_THICK_DASHED),
             Self::ThickCornerGapped => Block::bordered().border_set(BORDER_THICK_CORNER_GAPPED),
+            Self::Ascii => Block::bordered().border_set(BORDER_ASCII),
         }
     }
 }
@@ -241,6 +281,20 @@ pub const BORDER_THICK_CORNER_GAPPED: border::Set = border::Set {
     horizontal_bottom: "━",
 };
 
+/// Border set using only ASCII characters
+///
+/// Works on any terminal, including those without Unicode box-drawing support.
+pub const BORDER_ASCII: border::Set = border::Set {
+    top_left: "+",
+    top_right: "+",
+    bottom_left: "+",
+    bottom_right: "+",
+    vertical_left: "|",
+    vertical_right: "|",
+    horizontal_top: "-",
+    horizontal_bottom: "-",
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -266,6 +320,7 @@ mod tests {
             BorderStyle::ThickRounded,
             BorderStyle::ThickDashed,
             BorderStyle::ThickCornerGapped,
+            BorderStyle::Ascii,
         ];
 
         for style in &styles {
@@ -291,6 +346,7 @@ mod tests {
             BORDER_THICK_ROUNDED,
             BORDER_THICK_DASHED,
             BORDER_THICK_CORNER_GAPPED,
+            BORDER_ASCII,
         ];
 
         for set in &sets {
@@ -305,5 +361,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn border_ascii_is_ascii() {
+        let set = BORDER_ASCII;
+        for part in [
+            set.top_left,
+            set.top_right,
+            set.bottom_left,
+            set.bottom_right,
+            set.vertical_left,
+            set.vertical_right,
+            set.horizontal_top,
+            set.horizontal_bottom,
+        ] {
+            assert!(part.is_ascii());
+        }
+    }
+
+    #[test]
+    fn border_style_round_trips_through_strings() {
+        assert_eq!(BorderStyle::ALL.len(), 13);
+        for style in BorderStyle::iter() {
+            assert_eq!(style.to_string().parse(), Ok(style));
+        }
+    }
+
+    #[test]
+    fn border_style_parse() {
+        assert_eq!("rounded-dashed".parse(), Ok(BorderStyle::RoundedDashed));
+        assert_eq!("Thick".parse(), Ok(BorderStyle::Thick));
+        assert_eq!(format!("{:>8}", BorderStyle::Thick), "   thick");
+        let err = "wavy".parse::<BorderStyle>().unwrap_err();
+        assert!(err.to_string().contains("double-line-rounded"));
+    }
+
     // Note: Title alignment and position tests are in the `title` module
 }
@@ -0,0 +1,516 @@
+//! Owned chart configuration for loading charts from files.
+//!
+//! [`PieChart`] borrows its labels and markers, which makes it convenient to
+//! build in code but impossible to deserialize directly. [`PieChartConfig`]
+//! and [`SliceConfig`] hold the same options as owned data. With the `serde`
+//! feature enabled they implement `Serialize` and `Deserialize`, so a whole
+//! chart can be stored in a TOML or JSON file and turned into a widget with
+//! [`PieChartConfig::chart`].
+//!
+//! # Examples
+//!
+//! ```
+//! use ratatui::style::Color;
+//! use tui_piechart::config::{PieChartConfig, SliceConfig};
+//! use tui_piechart::border_style::BorderStyle;
+//! use tui_piechart::LegendPosition;
+//!
+//! let config = PieChartConfig {
+//!     slices: vec![
+//!         SliceConfig::new("Rust", 45.0).color(Color::Red),
+//!         SliceConfig::new("Go", 30.0),
+//!     ],
+//!     border_style: Some(BorderStyle::Rounded),
+//!     title: Some("Languages".to_string()),
+//!     legend_position: LegendPosition::Bottom,
+//!     ..Default::default()
+//! };
+//!
+//! let chart = config.chart();
+//! ```
+
+use ratatui::layout::{HorizontalAlignment, VerticalAlignment};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::Block;
+
+use crate::border_style::BorderStyle;
+use crate::legend::{
+    LegendAlignment, LegendConfig, LegendFlow, LegendLayout, LegendOrder, LegendPosition,
+    LegendTable, LegendTruncation,
+};
+use crate::palette::Palette;
+use crate::pattern::FillPattern;
+use crate::placeholder::Placeholder;
+use crate::title::{BlockExt, Title, TitleAlignment, TitlePosition, TitleStyle};
+use crate::{symbols, PieChart, PieRadius, PieSlice, Resolution, SliceOrder, ValuePolicy};
+
+/// Owned configuration of a single pie slice.
+///
+/// Converted into a [`PieSlice`] with [`slice`](Self::slice).
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct SliceConfig {
+    /// Label of the slice
+    pub label: String,
+    /// Value of the slice
+    pub value: f64,
+    /// Color of the slice, or `None` to use the chart's palette
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub color: Option<Color>,
+    /// Fill pattern of the slice, or `None` for the chart's choice
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub pattern: Option<FillPattern>,
+    /// Style patched over the slice color for pie cells and the legend entry
+    pub style: Style,
+    /// Style patched over `style` for the legend entry only
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub legend_style: Option<Style>,
+    /// Character used to draw this slice, overriding the chart's
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub pie_char: Option<char>,
+    /// Legend marker for this slice, overriding the chart's
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub legend_marker: Option<String>,
+}
+
+impl SliceConfig {
+    /// Creates a slice configuration with the given label and value and no
+    /// color.
+    #[must_use]
+    pub fn new(label: impl Into<String>, value: f64) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the color of the slice.
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Creates a [`PieSlice`] borrowing from this configuration.
+    #[must_use]
+    pub fn slice(&self) -> PieSlice<'_> {
+        let mut slice = match self.color {
+            Some(color) => PieSlice::new(&self.label, self.value, color),
+            None => PieSlice::auto(&self.label, self.value),
+        };
+        slice.pattern = self.pattern;
+        slice.style = self.style;
+        slice.legend_style = self.legend_style;
+        slice.pie_char = self.pie_char;
+        slice.legend_marker = self.legend_marker.as_deref();
+        slice
+    }
+}
+
+/// A palette that owns its custom colors.
+///
+/// The owned counterpart of [`Palette`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum PaletteConfig {
+    /// See [`Palette::Categorical`]
+    #[default]
+    Categorical,
+    /// See [`Palette::Tableau`]
+    Tableau,
+    /// See [`Palette::Pastel`]
+    Pastel,
+    /// See [`Palette::OkabeIto`]
+    OkabeIto,
+    /// See [`Palette::Custom`]
+    Custom(Vec<Color>),
+}
+
+impl PaletteConfig {
+    /// Returns the [`Palette`] borrowing from this configuration.
+    #[must_use]
+    pub fn palette(&self) -> Palette<'_> {
+        match self {
+            Self::Categorical => Palette::Categorical,
+            Self::Tableau => Palette::Tableau,
+            Self::Pastel => Palette::Pastel,
+            Self::OkabeIto => Palette::OkabeIto,
+            Self::Custom(colors) => Palette::Custom(colors),
+        }
+    }
+}
+
+/// Owned configuration of the placeholder shown when a chart is empty.
+///
+/// The owned counterpart of [`Placeholder`], converted with
+/// [`placeholder`](Self::placeholder).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct PlaceholderConfig {
+    /// Message drawn in the middle of the pie area; empty for none
+    pub text: String,
+    /// Style of the message
+    pub text_style: Style,
+    /// Style of the outline ring, or `None` for no ring
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub ring: Option<Style>,
+    /// Character used to draw the ring in standard resolution
+    pub ring_char: char,
+    /// Whether the legend is still drawn, listing slices at 0%
+    pub show_legend: bool,
+}
+
+impl Default for PlaceholderConfig {
+    fn default() -> Self {
+        let placeholder = Placeholder::default();
+        Self {
+            text: placeholder.text.to_string(),
+            text_style: placeholder.text_style,
+            ring: placeholder.ring,
+            ring_char: placeholder.ring_char,
+            show_legend: placeholder.show_legend,
+        }
+    }
+}
+
+impl PlaceholderConfig {
+    /// Creates a [`Placeholder`] borrowing from this configuration.
+    #[must_use]
+    pub fn placeholder(&self) -> Placeholder<'_> {
+        Placeholder {
+            text: &self.text,
+            text_style: self.text_style,
+            ring: self.ring,
+            ring_char: self.ring_char,
+            show_legend: self.show_legend,
+        }
+    }
+}
+
+/// Owned configuration of a whole pie chart.
+///
+/// Every field has a default matching [`PieChart::default`], so configuration
+/// files only need to list the options they change. Converted into a
+/// [`PieChart`] with [`chart`](Self::chart).
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::struct_excessive_bools)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct PieChartConfig {
+    /// Slices of the chart
+    pub slices: Vec<SliceConfig>,
+    /// Base style of the chart
+    pub style: Style,
+    /// Whether to show the legend
+    pub show_legend: bool,
+    /// Whether to show percentages in the legend
+    pub show_percentages: bool,
+    /// Character used to draw the pie
+    pub pie_char: char,
+    /// Marker used for legend items
+    pub legend_marker: String,
+    /// Rendering resolution
+    pub resolution: Resolution,
+    /// Size of the pie
+    pub radius: PieRadius,
+    /// Horizontal position of the pie in its area
+    pub pie_alignment: HorizontalAlignment,
+    /// Vertical position of the pie in its area
+    pub pie_vertical_alignment: VerticalAlignment,
+    /// Position of the legend
+    pub legend_position: LegendPosition,
+    /// Layout of the legend
+    pub legend_layout: LegendLayout,
+    /// Alignment of legend items
+    pub legend_alignment: LegendAlignment,
+    /// Palette for slices without a color
+    pub palette: PaletteConfig,
+    /// Whether slices get distinct fill patterns automatically
+    pub auto_patterns: bool,
+    /// How NaN, infinite and negative values are treated
+    pub value_policy: ValuePolicy,
+    /// What to draw when there is nothing to draw, or `None` for nothing
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub placeholder: Option<PlaceholderConfig>,
+    /// Order in which slices are drawn
+    pub slice_order: SliceOrder,
+    /// Order of legend items
+    pub legend_order: LegendOrder,
+    /// Options for the table legend layout
+    pub legend_table: LegendTable,
+    /// Number of grid legend columns, or `0` to fit as many as possible
+    pub legend_columns: u16,
+    /// Order in which the grid legend fills its cells
+    pub legend_flow: LegendFlow,
+    /// Style patched onto the legend entry of the selected slice
+    pub highlight_style: Style,
+    /// Size and spacing of the legend
+    pub legend_config: LegendConfig,
+    /// Header drawn with the legend, or `None` for no header
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub legend_title: Option<String>,
+    /// Horizontal alignment of the legend header
+    pub legend_title_alignment: TitleAlignment,
+    /// Whether the legend header is drawn above or below the entries
+    pub legend_title_position: TitlePosition,
+    /// Style of the legend header
+    pub legend_title_style: Style,
+    /// Whether a rule separates the legend header from the entries
+    pub legend_separator: bool,
+    /// Border style around the legend, or `None` for no border
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub legend_border: Option<BorderStyle>,
+    /// Width of the percentage bar in each legend entry, or `0` for no bars
+    pub legend_bar_width: u16,
+    /// How legend labels are shortened when an entry does not fit
+    pub legend_truncation: LegendTruncation,
+    /// Fill behind the legend, or `None` to draw it straight over the chart
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub legend_background: Option<Style>,
+    /// Border style of the surrounding block, or `None` for no border
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub border_style: Option<BorderStyle>,
+    /// Block title, or `None` for no title
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub title: Option<String>,
+    /// Font style of the title
+    pub title_style: TitleStyle,
+    /// Horizontal alignment of the title
+    pub title_alignment: TitleAlignment,
+    /// Vertical position of the title
+    pub title_position: TitlePosition,
+}
+
+impl Default for PieChartConfig {
+    fn default() -> Self {
+        Self {
+            slices: Vec::new(),
+            style: Style::default(),
+            show_legend: true,
+            show_percentages: true,
+            pie_char: symbols::PIE_CHAR,
+            legend_marker: symbols::LEGEND_MARKER.to_string(),
+            resolution: Resolution::default(),
+            radius: PieRadius::default(),
+            pie_alignment: HorizontalAlignment::Center,
+            pie_vertical_alignment: VerticalAlignment::Center,
+            legend_position: LegendPosition::default(),
+            legend_layout: LegendLayout::default(),
+            legend_alignment: LegendAlignment::default(),
+            palette: PaletteConfig::default(),
+            auto_patterns: false,
+            value_policy: ValuePolicy::default(),
+            placeholder: None,
+            slice_order: SliceOrder::default(),
+            legend_order: LegendOrder::default(),
+            legend_table: LegendTable::default(),
+            legend_columns: 0,
+            legend_flow: LegendFlow::default(),
+            highlight_style: Style::new().add_modifier(Modifier::REVERSED),
+            legend_config: LegendConfig::default(),
+            legend_title: None,
+            legend_title_alignment: TitleAlignment::default(),
+            legend_title_position: TitlePosition::default(),
+            legend_title_style: Style::new().add_modifier(Modifier::BOLD),
+            legend_separator: false,
+            legend_border: None,
+            legend_bar_width: 0,
+            legend_truncation: LegendTruncation::default(),
+            legend_background: None,
+            border_style: None,
+            title: None,
+            title_style: TitleStyle::default(),
+            title_alignment: TitleAlignment::default(),
+            title_position: TitlePosition::default(),
+        }
+    }
+}
+
+impl PieChartConfig {
+    /// Creates a [`PieChart`] borrowing from this configuration.
+    ///
+    /// A block is added when a border style or a title is set.
+    #[must_use]
+    pub fn chart(&self) -> PieChart<'_> {
+        let mut chart = PieChart::new(self.slices.iter().map(SliceConfig::slice).collect())
+            .style(self.style)
+            .show_legend(self.show_legend)
+            .show_percentages(self.show_percentages)
+            .pie_char(self.pie_char)
+            .legend_marker(&self.legend_marker)
+            .resolution(self.resolution)
+            .radius(self.radius)
+            .pie_alignment(self.pie_alignment, self.pie_vertical_alignment)
+            .legend_position(self.legend_position)
+            .legend_layout(self.legend_layout)
+            .legend_alignment(self.legend_alignment)
+            .palette(self.palette.palette())
+            .auto_patterns(self.auto_patterns)
+            .value_policy(self.value_policy)
+            .slice_order(self.slice_order)
+            .legend_order(self.legend_order)
+            .legend_table(self.legend_table)
+            .legend_columns(self.legend_columns)
+            .legend_flow(self.legend_flow)
+            .highlight_style(self.highlight_style)
+            .legend_config(self.legend_config)
+            .legend_title_style(self.legend_title_style)
+            .legend_separator(self.legend_separator)
+            .legend_bars(self.legend_bar_width)
+            .legend_truncation(self.legend_truncation);
+        if let Some(placeholder) = &self.placeholder {
+            chart = chart.placeholder(placeholder.placeholder());
+        }
+        if let Some(title) = &self.legend_title {
+            chart = chart.legend_title(Self::title(
+                title.as_str(),
+                self.legend_title_alignment,
+                self.legend_title_position,
+            ));
+        }
+        if let Some(border) = self.legend_border {
+            chart = chart.legend_border(border);
+        }
+        if let Some(style) = self.legend_background {
+            chart = chart.legend_background(style);
+        }
+        if let Some(block) = self.block() {
+            chart = chart.block(block);
+        }
+        chart
+    }
+
+    fn block(&self) -> Option<Block<'static>> {
+        if self.border_style.is_none() && self.title.is_none() {
+            return None;
+        }
+        let block = self
+            .border_style
+            .map_or_else(Block::new, BorderStyle::block);
+        let Some(text) = &self.title else {
+            return Some(block);
+        };
+        let title = Self::title(
+            self.title_style.apply(text),
+            self.title_alignment,
+            self.title_position,
+        );
+        Some(block.apply_title(title))
+    }
+
+    fn title(text: impl Into<String>, alignment: TitleAlignment, position: TitlePosition) -> Title {
+        let title = Title::new(text);
+        let title = match alignment {
+            TitleAlignment::Start => title.left(),
+            TitleAlignment::Center => title.center(),
+            TitleAlignment::End => title.right(),
+        };
+        match position {
+            TitlePosition::Top => title.top(),
+            TitlePosition::Bottom => title.bottom(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unnameable_test_items)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_default_matches_chart_default() {
+        let config = PieChartConfig::default();
+        let chart = PieChart::default();
+        assert_eq!(config.chart(), chart);
+    }
+
+    #[test]
+    fn slice_config_into_slice() {
+        let mut config = SliceConfig::new("Rust", 45.0).color(Color::Red);
+        config.pattern = Some(FillPattern::Dotted);
+        config.legend_marker = Some("→".to_string());
+        config.style = Style::new().bg(Color::Black);
+        config.legend_style = Some(Style::new().fg(Color::White));
+        let slice = config.slice();
+        assert_eq!(slice.label(), "Rust");
+        assert!((slice.value() - 45.0).abs() < f64::EPSILON);
+        assert_eq!(slice.color(), Color::Red);
+        assert_eq!(slice.fill_pattern(), Some(FillPattern::Dotted));
+        assert_eq!(slice.legend_marker, Some("→"));
+        assert_eq!(slice.style, Style::new().bg(Color::Black));
+        assert_eq!(slice.legend_style, Some(Style::new().fg(Color::White)));
+    }
+
+    #[test]
+    fn slice_config_without_color_is_auto() {
+        assert!(!SliceConfig::new("Go", 1.0).slice().has_color());
+    }
+
+    #[test]
+    fn palette_config_custom_borrows_colors() {
+        let config = PaletteConfig::Custom(vec![Color::White, Color::Black]);
+        assert_eq!(config.palette().color(1), Color::Black);
+        assert_eq!(PaletteConfig::OkabeIto.palette(), Palette::OkabeIto);
+    }
+
+    #[test]
+    fn placeholder_config_into_placeholder() {
+        assert_eq!(
+            PlaceholderConfig::default().placeholder(),
+            Placeholder::default()
+        );
+        let config = PlaceholderConfig {
+            text: "Waiting".to_string(),
+            ring: Some(Style::new().fg(Color::DarkGray)),
+            show_legend: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            config.placeholder(),
+            Placeholder::new("Waiting")
+                .ring(Color::DarkGray)
+                .show_legend(true)
+        );
+    }
+
+    #[test]
+    fn config_block_only_when_needed() {
+        let mut config = PieChartConfig::default();
+        assert!(config.block().is_none());
+        config.title = Some("Stats".to_string());
+        assert!(config.block().is_some());
+        config.title = None;
+        config.border_style = Some(BorderStyle::Rounded);
+        assert_eq!(config.block(), Some(BorderStyle::Rounded.block()));
+    }
+
+    #[test]
+    fn config_legend_title_keeps_alignment_and_position() {
+        let config = PieChartConfig {
+            legend_title: Some("Share".to_string()),
+            legend_title_alignment: TitleAlignment::End,
+            legend_title_position: TitlePosition::Bottom,
+            ..Default::default()
+        };
+        assert_eq!(
+            config.chart(),
+            PieChart::default().legend_title(Title::new("Share").right().bottom())
+        );
+    }
+}
@@ -0,0 +1,233 @@
+//! Error types.
+//!
+//! # Examples
+//!
+//! ```
+//! use tui_piechart::border_style::BorderStyle;
+//! use tui_piechart::error::ParseOptionError;
+//!
+//! let err: ParseOptionError = "wavy".parse::<BorderStyle>().unwrap_err();
+//! assert_eq!(err.input(), "wavy");
+//! assert!(err.expected().contains(&"rounded-dashed"));
+//! ```
+//!
+//! ```
+//! use tui_piechart::error::ValueErrorKind;
+//! use tui_piechart::{PieChart, PieSlice};
+//!
+//! let err = PieChart::try_new(vec![
+//!     PieSlice::auto("Rust", 45.0),
+//!     PieSlice::auto("Go", f64::NAN),
+//! ])
+//! .unwrap_err();
+//! assert_eq!(err.index(), 1);
+//! assert_eq!(err.label(), "Go");
+//! assert_eq!(err.kind(), ValueErrorKind::NaN);
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+/// Error returned when parsing an option enum from a string fails.
+///
+/// Produced by the `FromStr` implementations of option enums such as
+/// [`BorderStyle`](crate::border_style::BorderStyle) and
+/// [`LegendPosition`](crate::LegendPosition). The message names the option,
+/// the rejected input, and every accepted value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOptionError {
+    option: &'static str,
+    input: String,
+    expected: &'static [&'static str],
+}
+
+impl ParseOptionError {
+    pub(crate) fn new(
+        option: &'static str,
+        input: &str,
+        expected: &'static [&'static str],
+    ) -> Self {
+        Self {
+            option,
+            input: input.to_string(),
+            expected,
+        }
+    }
+
+    /// Returns the name of the type that failed to parse, e.g. `"BorderStyle"`.
+    #[must_use]
+    pub const fn option(&self) -> &'static str {
+        self.option
+    }
+
+    /// Returns the rejected input.
+    #[must_use]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Returns the canonical names that would have been accepted.
+    #[must_use]
+    pub const fn expected(&self) -> &'static [&'static str] {
+        self.expected
+    }
+}
+
+impl fmt::Display for ParseOptionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid {} {:?}, expected one of: {}",
+            self.option,
+            self.input,
+            self.expected.join(", ")
+        )
+    }
+}
+
+impl Error for ParseOptionError {}
+
+/// The reason a slice value cannot be drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueErrorKind {
+    /// The value is NaN
+    NaN,
+    /// The value is positive or negative infinity
+    Infinite,
+    /// The value is less than zero
+    Negative,
+}
+
+impl ValueErrorKind {
+    /// Classifies `value`, returning `None` if it is finite and not negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::error::ValueErrorKind;
+    ///
+    /// assert_eq!(ValueErrorKind::of(12.5), None);
+    /// assert_eq!(ValueErrorKind::of(-1.0), Some(ValueErrorKind::Negative));
+    /// assert_eq!(ValueErrorKind::of(f64::INFINITY), Some(ValueErrorKind::Infinite));
+    /// ```
+    #[must_use]
+    pub fn of(value: f64) -> Option<Self> {
+        if value.is_nan() {
+            Some(Self::NaN)
+        } else if value.is_infinite() {
+            Some(Self::Infinite)
+        } else if value < 0.0 {
+            Some(Self::Negative)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for ValueErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NaN => "NaN",
+            Self::Infinite => "infinite",
+            Self::Negative => "negative",
+        })
+    }
+}
+
+/// Error returned when a slice has a value that cannot be drawn.
+///
+/// Produced by [`PieChart::validate`](crate::PieChart::validate) and
+/// [`PieChart::try_new`](crate::PieChart::try_new) for the first slice whose
+/// value is NaN, infinite, or negative.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SliceValueError {
+    index: usize,
+    label: String,
+    value: f64,
+    kind: ValueErrorKind,
+}
+
+impl SliceValueError {
+    pub(crate) fn new(index: usize, label: &str, value: f64, kind: ValueErrorKind) -> Self {
+        Self {
+            index,
+            label: label.to_string(),
+            value,
+            kind,
+        }
+    }
+
+    /// Returns the position of the offending slice in the chart.
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the label of the offending slice.
+    #[must_use]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the offending value.
+    #[must_use]
+    pub const fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns why the value was rejected.
+    #[must_use]
+    pub const fn kind(&self) -> ValueErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for SliceValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "slice {} ({:?}) has a {} value: {}",
+            self.index, self.label, self.kind, self.value
+        )
+    }
+}
+
+impl Error for SliceValueError {}
+
+#[cfg(test)]
+#[allow(unnameable_test_items)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_option_error_message() {
+        let err = ParseOptionError::new("Resolution", "hd", &["standard", "braille"]);
+        assert_eq!(
+            err.to_string(),
+            r#"invalid Resolution "hd", expected one of: standard, braille"#
+        );
+        assert_eq!(err.option(), "Resolution");
+        assert_eq!(err.input(), "hd");
+    }
+
+    #[test]
+    fn value_error_kind_of() {
+        assert_eq!(ValueErrorKind::of(0.0), None);
+        assert_eq!(ValueErrorKind::of(-0.0), None);
+        assert_eq!(ValueErrorKind::of(f64::NAN), Some(ValueErrorKind::NaN));
+        assert_eq!(
+            ValueErrorKind::of(f64::NEG_INFINITY),
+            Some(ValueErrorKind::Infinite)
+        );
+        assert_eq!(ValueErrorKind::of(-0.5), Some(ValueErrorKind::Negative));
+    }
+
+    #[test]
+    fn slice_value_error_message() {
+        let err = SliceValueError::new(2, "Go", -3.0, ValueErrorKind::Negative);
+        assert_eq!(
+            err.to_string(),
+            r#"slice 2 ("Go") has a negative value: -3"#
+        );
+    }
+}
@@ -1,7 +1,8 @@
 //! Legend positioning and layout configuration for pie charts.
 //!
 //! This module provides types and functionality for controlling where and how
-//! the legend is displayed relative to the pie chart.
+//! the legend is displayed relative to the pie chart, and the [`PieLegend`]
+//! widget that draws the legend elsewhere in the layout.
 //!
 //! # Examples
 //!
@@ -22,11 +23,24 @@
 //!     .legend_alignment(LegendAlignment::Center);
 //! ```
 
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Rect, Size};
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::{Block, StatefulWidget, Widget};
+use std::borrow::Cow;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::title::Title;
+use crate::{
+    Entries, Palette, PieChart, PieChartState, PieChartTheme, PieDataSource, PieSlice, SliceOrder,
+    ValuePolicy,
+};
+
 /// Position of the legend relative to the pie chart.
 ///
 /// Controls where the legend appears in relation to the pie chart visualization.
 /// The legend can be positioned on any of the four sides: right (default), left,
-/// top, or bottom.
+/// top, or bottom, or drawn over one of the corners of the pie.
 ///
 /// # Examples
 ///
@@ -46,9 +60,15 @@
 /// The legend position affects how space is allocated:
 /// - **Right/Left**: Legend takes a portion of horizontal space
 /// - **Top/Bottom**: Legend takes a portion of vertical space
+/// - **Corners**: Legend is drawn over the pie, which keeps the whole area
 ///
 /// The chart automatically adjusts its size to accommodate the legend.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum LegendPosition {
     /// Legend on the right side (default)
     ///
@@ -74,6 +94,58 @@ pub enum LegendPosition {
     /// The legend appears below the pie chart. Works well with horizontal layout
     /// when vertical space is limited.
     Bottom,
+
+    /// Legend over the top left corner of the pie
+    ///
+    /// Overlay positions draw the legend over a corner of the chart area
+    /// instead of taking space from the pie, which keeps its full size in
+    /// small panes. Set a [`legend_background`](crate::PieChart::legend_background)
+    /// to keep the entries readable over the pie.
+    TopLeft,
+
+    /// Legend over the top right corner of the pie
+    TopRight,
+
+    /// Legend over the bottom left corner of the pie
+    BottomLeft,
+
+    /// Legend over the bottom right corner of the pie
+    BottomRight,
+
+    /// Legend placed to fit the area
+    ///
+    /// The legend goes on the right in wide areas and at the bottom in tall
+    /// ones, or on the other side if that is the only one with room for it.
+    /// When it fits neither way it is abbreviated to markers and labels, and
+    /// only when that does not fit either is it hidden. The choice is made
+    /// again on every render, so the chart adapts as its pane is resized.
+    Auto,
+}
+
+crate::macros::option::option_enum! {
+    LegendPosition {
+        Right => "right",
+        Left => "left",
+        Top => "top",
+        Bottom => "bottom",
+        TopLeft => "top-left",
+        TopRight => "top-right",
+        BottomLeft => "bottom-left",
+        BottomRight => "bottom-right",
+        Auto => "auto",
+    }
+}
+
+impl LegendPosition {
+    /// Returns `true` for the corner positions, where the legend is drawn
+    /// over the pie instead of beside it.
+    #[must_use]
+    pub const fn is_overlay(self) -> bool {
+        matches!(
+            self,
+            Self::TopLeft | Self::TopRight | Self::BottomLeft | Self::BottomRight
+        )
+    }
 }
 
 /// Layout mode for the legend.
@@ -104,7 +176,14 @@ pub enum LegendPosition {
 ///   with longer labels or when vertical space is available.
 /// - **Horizontal**: All legend items on one line. Best for compact displays
 ///   or when used with Top/Bottom positions.
+/// - **Table**: One row per item with aligned columns. Best when values or
+///   percentages need to be compared at a glance.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum LegendLayout {
     /// Vertical layout - items stacked vertically (default)
     ///
@@ -128,7 +207,269 @@ pub enum LegendLayout {
     ///
     /// This layout is more compact and works well with Top/Bottom positions.
     /// The chart automatically calculates required width to prevent item cutoff.
+    /// Items that do not fit wrap onto further rows, up to three, after which
+    /// the rest are summarized as "+N more".
     Horizontal,
+
+    /// Table layout - one row per item with aligned columns
+    ///
+    /// Markers and labels are left-aligned, values and percentages are
+    /// right-aligned, and an optional header and total row can be added with
+    /// [`LegendTable`]:
+    /// ```text
+    ///   Label   Value      %
+    /// ● Rust       45  45.0%
+    /// ● Go          5   5.0%
+    ///   Total     100 100.0%
+    /// ```
+    Table,
+}
+
+crate::macros::option::option_enum! {
+    LegendLayout {
+        Vertical => "vertical",
+        Horizontal => "horizontal",
+        Table => "table",
+    }
+}
+
+/// Options for the [`LegendLayout::Table`] legend.
+///
+/// The default table has a value column and, when the chart shows
+/// percentages, a percent column, with no header or footer. Values are
+/// printed in their shortest form unless a precision is set.
+///
+/// The header and footer labels are fixed: the header reads "Label",
+/// "Value" and "%", and the footer is labeled "Total".
+///
+/// # Examples
+///
+/// ```
+/// use tui_piechart::{LegendLayout, LegendTable, PieChart, PieSlice};
+///
+/// let chart = PieChart::new(vec![PieSlice::auto("Rust", 45.0), PieSlice::auto("Go", 30.0)])
+///     .legend_layout(LegendLayout::Table)
+///     .legend_table(LegendTable::new().header(true).footer(true).precision(1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct LegendTable {
+    /// Whether a header row names the columns
+    pub(crate) header: bool,
+    /// Whether a footer row shows the total
+    pub(crate) footer: bool,
+    /// Whether the value column is shown
+    pub(crate) values: bool,
+    /// Number of decimals for values, or `None` for the shortest form
+    pub(crate) precision: Option<usize>,
+    /// Style of the header and footer rows
+    pub(crate) style: Style,
+}
+
+impl Default for LegendTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LegendTable {
+    /// Creates the default table options.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            header: false,
+            footer: false,
+            values: true,
+            precision: None,
+            style: Style::new().add_modifier(Modifier::BOLD),
+        }
+    }
+
+    /// Sets whether a header row names the columns "Label", "Value" and
+    /// "%".
+    #[must_use]
+    pub const fn header(mut self, show: bool) -> Self {
+        self.header = show;
+        self
+    }
+
+    /// Sets whether a footer row, labeled "Total", shows the total of all
+    /// values.
+    #[must_use]
+    pub const fn footer(mut self, show: bool) -> Self {
+        self.footer = show;
+        self
+    }
+
+    /// Sets whether the absolute value column is shown.
+    ///
+    /// The percent column follows
+    /// [`PieChart::show_percentages`](crate::PieChart::show_percentages).
+    #[must_use]
+    pub const fn values(mut self, show: bool) -> Self {
+        self.values = show;
+        self
+    }
+
+    /// Prints values with a fixed number of decimals.
+    #[must_use]
+    pub const fn precision(mut self, decimals: usize) -> Self {
+        self.precision = Some(decimals);
+        self
+    }
+
+    /// Sets the style of the header and footer rows.
+    ///
+    /// Bold by default.
+    #[must_use]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Formats a value for the value column.
+    pub(crate) fn format_value(&self, value: f64) -> String {
+        match self.precision {
+            Some(decimals) => format!("{value:.decimals$}"),
+            None => value.to_string(),
+        }
+    }
+}
+
+/// Space the legend takes up and how tightly its entries are packed.
+///
+/// The defaults give a vertical legend beside the pie at most a third of the
+/// width, at least 20 columns, and a blank line between entries, with one
+/// line of padding around the legend and one cell between it and the pie.
+///
+/// # Examples
+///
+/// ```
+/// use tui_piechart::{LegendConfig, PieChart, PieSlice};
+///
+/// // A compact legend: one entry per line, no padding
+/// let chart = PieChart::new(vec![PieSlice::auto("Rust", 45.0), PieSlice::auto("Go", 30.0)])
+///     .legend_config(LegendConfig::new().item_spacing(0).padding(0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct LegendConfig {
+    /// A legend beside the pie takes at most `1 / vertical_max_ratio` of the width
+    pub(crate) vertical_max_ratio: u16,
+    /// Minimum width of a legend beside the pie
+    pub(crate) vertical_min_width: u16,
+    /// Maximum width of a horizontal legend beside the pie
+    pub(crate) horizontal_max_width: u16,
+    /// Maximum height of a grid or table legend above or below the pie
+    pub(crate) vertical_max_height: u16,
+    /// Cells between the pie and the legend
+    pub(crate) spacing: u16,
+    /// Padding around the legend
+    pub(crate) padding: u16,
+    /// Blank lines between entries of a vertical legend
+    pub(crate) item_spacing: u16,
+}
+
+impl Default for LegendConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LegendConfig {
+    /// Creates the default legend geometry.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            vertical_max_ratio: 3,
+            vertical_min_width: 20,
+            horizontal_max_width: 60,
+            vertical_max_height: 9,
+            spacing: 1,
+            padding: 1,
+            item_spacing: 1,
+        }
+    }
+
+    /// Limits a legend beside the pie to `1 / ratio` of the available width.
+    ///
+    /// Defaults to `3`. A ratio of `0` is treated as `1`.
+    #[must_use]
+    pub const fn vertical_max_ratio(mut self, ratio: u16) -> Self {
+        self.vertical_max_ratio = ratio;
+        self
+    }
+
+    /// Sets the minimum width of a legend beside the pie, which takes
+    /// precedence over the ratio.
+    ///
+    /// Defaults to `20`.
+    #[must_use]
+    pub const fn vertical_min_width(mut self, width: u16) -> Self {
+        self.vertical_min_width = width;
+        self
+    }
+
+    /// Sets the maximum width of a horizontal legend beside the pie.
+    ///
+    /// Defaults to `60`.
+    #[must_use]
+    pub const fn horizontal_max_width(mut self, width: u16) -> Self {
+        self.horizontal_max_width = width;
+        self
+    }
+
+    /// Sets the maximum height, padding included, of a grid or table legend
+    /// above or below the pie. A legend title and border get their lines on
+    /// top of it.
+    ///
+    /// Defaults to `9`, enough for four rows of a grid.
+    #[must_use]
+    pub const fn vertical_max_height(mut self, height: u16) -> Self {
+        self.vertical_max_height = height;
+        self
+    }
+
+    /// Sets the number of cells between the pie and the legend.
+    ///
+    /// Defaults to `1`.
+    #[must_use]
+    pub const fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the padding around the legend.
+    ///
+    /// Defaults to `1`.
+    #[must_use]
+    pub const fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the number of blank lines between the entries of a vertical or
+    /// grid legend.
+    ///
+    /// Defaults to `1`; `0` puts one entry on every line.
+    #[must_use]
+    pub const fn item_spacing(mut self, lines: u16) -> Self {
+        self.item_spacing = lines;
+        self
+    }
+
+    /// Returns the number of lines from one vertical entry to the next.
+    pub(crate) const fn item_pitch(&self) -> u16 {
+        self.item_spacing.saturating_add(1)
+    }
 }
 
 /// Alignment of legend items within the legend area.
@@ -159,6 +500,11 @@ pub enum LegendLayout {
 /// - **Center**: Legend items are centered within the legend area
 /// - **Right**: Legend items align to the right edge
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum LegendAlignment {
     /// Left alignment (default)
     ///
@@ -179,11 +525,514 @@ pub enum LegendAlignment {
     Right,
 }
 
+crate::macros::option::option_enum! {
+    LegendAlignment {
+        Left => "left",
+        Center => "center",
+        Right => "right",
+    }
+}
+
+/// Order in which a grid legend fills its cells.
+///
+/// A vertical legend above or below the pie is drawn as a grid. See
+/// [`PieChart::legend_columns`](crate::PieChart::legend_columns).
+///
+/// # Examples
+///
+/// ```
+/// use tui_piechart::{LegendFlow, LegendPosition, PieChart, PieSlice};
+///
+/// let slices = vec![PieSlice::auto("Rust", 45.0), PieSlice::auto("Go", 30.0)];
+///
+/// // Fill the first column top to bottom before starting the next one
+/// let chart = PieChart::new(slices)
+///     .legend_position(LegendPosition::Bottom)
+///     .legend_flow(LegendFlow::ColumnMajor);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum LegendFlow {
+    /// Left to right, then down to the next row (default)
+    ///
+    /// ```text
+    /// ● A  ● B  ● C
+    /// ● D  ● E
+    /// ```
+    #[default]
+    RowMajor,
+
+    /// Top to bottom, then across to the next column
+    ///
+    /// ```text
+    /// ● A  ● C  ● E
+    /// ● B  ● D
+    /// ```
+    ColumnMajor,
+}
+
+crate::macros::option::option_enum! {
+    LegendFlow {
+        RowMajor => "row-major",
+        ColumnMajor => "column-major",
+    }
+}
+
+/// How legend labels are shortened when an entry is wider than the legend.
+///
+/// Only the label gives way: the marker, bar and percentage of an entry stay
+/// whole, so the numbers remain readable in narrow legends. See
+/// [`PieChart::legend_truncation`](crate::PieChart::legend_truncation).
+///
+/// # Examples
+///
+/// ```
+/// use tui_piechart::{LegendTruncation, PieChart, PieSlice};
+///
+/// let slices = vec![PieSlice::auto("Rust", 45.0), PieSlice::auto("Go", 30.0)];
+///
+/// // Keep the start and end of long labels
+/// let chart = PieChart::new(slices).legend_truncation(LegendTruncation::Middle);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum LegendTruncation {
+    /// Cut the end of the label (default)
+    ///
+    /// ```text
+    /// ■ JavaSc… 45.0%
+    /// ```
+    #[default]
+    End,
+
+    /// Cut the middle of the label, keeping its start and end
+    ///
+    /// ```text
+    /// ■ Jav…ipt 45.0%
+    /// ```
+    Middle,
+
+    /// Leave the label out altogether
+    ///
+    /// ```text
+    /// ■ 45.0%
+    /// ```
+    Hide,
+}
+
+crate::macros::option::option_enum! {
+    LegendTruncation {
+        End => "end",
+        Middle => "middle",
+        Hide => "hide",
+    }
+}
+
+impl LegendTruncation {
+    /// Shortens `label` to at most `width` cells, marking the cut with "…".
+    pub(crate) fn truncate(self, label: &str, width: usize) -> Cow<'_, str> {
+        if label.width() <= width {
+            return label.into();
+        }
+        let Some(budget) = width.checked_sub(1) else {
+            return "".into();
+        };
+        match self {
+            Self::End => format!("{}…", take_width(label.chars(), budget)).into(),
+            Self::Middle => {
+                let head = take_width(label.chars(), budget.div_ceil(2));
+                let tail = take_width(label.chars().rev(), budget - head.width());
+                let tail: String = tail.chars().rev().collect();
+                format!("{head}…{tail}").into()
+            }
+            Self::Hide => "".into(),
+        }
+    }
+}
+
+/// Collects characters until the next would take the text past `width` cells.
+fn take_width(chars: impl Iterator<Item = char>, width: usize) -> String {
+    let mut used = 0;
+    chars
+        .take_while(|c| {
+            used += c.width().unwrap_or(0);
+            used <= width
+        })
+        .collect()
+}
+
+/// Order of legend items when the chart sorts its slices.
+///
+/// See [`PieChart::slice_order`](crate::PieChart::slice_order). Slices keep
+/// their color and pattern in either order.
+///
+/// # Examples
+///
+/// ```
+/// use tui_piechart::{LegendOrder, PieChart, PieSlice, SliceOrder};
+///
+/// let slices = vec![PieSlice::auto("Go", 30.0), PieSlice::auto("Rust", 45.0)];
+///
+/// // Largest slice first in the pie, but the legend keeps the input order
+/// let chart = PieChart::new(slices)
+///     .slice_order(SliceOrder::ValueDescending)
+///     .legend_order(LegendOrder::Insertion);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum LegendOrder {
+    /// Same order as the pie slices (default)
+    #[default]
+    Pie,
+
+    /// The order the slices were added in
+    Insertion,
+}
+
+crate::macros::option::option_enum! {
+    LegendOrder {
+        Pie => "pie",
+        Insertion => "insertion",
+    }
+}
+
+/// A legend drawn on its own, outside of a [`PieChart`].
+///
+/// `PieLegend` draws the same entries a chart's legend would, with the same
+/// markers, colors, ordering and layouts, into any area. Use it to put the
+/// legend in a separate part of the layout, such as a sidebar, and size that
+/// part with [`preferred_size`](Self::preferred_size).
+///
+/// A vertical legend is a single column that scrolls like the chart's, unless
+/// it is given more than one column with [`columns`](Self::columns). Like
+/// the chart, it can be rendered with a [`PieChartState`] to select a slice.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::Widget;
+/// use tui_piechart::{LegendLayout, PieChart, PieLegend, PieSlice};
+///
+/// let slices = vec![PieSlice::auto("Rust", 45.0), PieSlice::auto("Go", 30.0)];
+/// let chart = PieChart::new(slices.clone()).show_legend(false);
+/// let legend = PieLegend::new(slices).layout(LegendLayout::Table);
+///
+/// let size = legend.preferred_size();
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 40, 10));
+/// legend.render(Rect::new(0, 0, size.width, size.height), &mut buf);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PieLegend<'a, D = Vec<PieSlice<'a>>> {
+    /// Chart whose legend options and data are drawn
+    chart: PieChart<'a, D>,
+}
+
+impl Default for PieLegend<'_> {
+    fn default() -> Self {
+        Self::from(PieChart::default())
+    }
+}
+
+impl<'a> PieLegend<'a> {
+    /// Creates a legend for the given slices.
+    #[must_use]
+    pub fn new(slices: Vec<PieSlice<'a>>) -> Self {
+        Self::from(PieChart::new(slices))
+    }
+}
+
+impl<'a, D: PieDataSource> From<PieChart<'a, D>> for PieLegend<'a, D> {
+    /// Creates a legend with the data and legend options of a chart.
+    ///
+    /// A vertical legend above or below the pie stays a grid, any other one
+    /// becomes a single column.
+    fn from(chart: PieChart<'a, D>) -> Self {
+        let grid = matches!(
+            chart.options.legend_position,
+            LegendPosition::Top | LegendPosition::Bottom
+        );
+        if grid {
+            Self { chart }
+        } else {
+            Self {
+                chart: chart.legend_columns(1),
+            }
+        }
+    }
+}
+
+impl<'a, D: PieDataSource> PieLegend<'a, D> {
+    /// Creates a legend that reads its slices from a
+    /// [`PieDataSource`].
+    #[must_use]
+    pub fn from_source(source: D) -> Self {
+        Self::from(PieChart::from_source(source))
+    }
+
+    /// Wraps the legend in a block.
+    #[must_use]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.chart = self.chart.block(block);
+        self
+    }
+
+    /// Sets the base style of the legend area.
+    #[must_use]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.chart = self.chart.style(style);
+        self
+    }
+
+    /// Sets whether entries show their percentage.
+    #[must_use]
+    pub const fn show_percentages(mut self, show: bool) -> Self {
+        self.chart.options.show_percentages = show;
+        self
+    }
+
+    /// Sets the marker drawn before each label.
+    #[must_use]
+    pub const fn marker(mut self, marker: &'a str) -> Self {
+        self.chart.options.legend_marker = marker;
+        self
+    }
+
+    /// Sets how entries are laid out.
+    #[must_use]
+    pub const fn layout(mut self, layout: LegendLayout) -> Self {
+        self.chart.options.legend_layout = layout;
+        self
+    }
+
+    /// Sets how entries are aligned within the legend area.
+    #[must_use]
+    pub const fn alignment(mut self, alignment: LegendAlignment) -> Self {
+        self.chart.options.legend_alignment = alignment;
+        self
+    }
+
+    /// Sets the table options used by [`LegendLayout::Table`].
+    #[must_use]
+    pub const fn table(mut self, table: LegendTable) -> Self {
+        self.chart.options.legend_table = table;
+        self
+    }
+
+    /// Lays a vertical legend out in `columns` columns, or as many as fit
+    /// with `0`.
+    ///
+    /// A single column (the default) scrolls when it has more entries than
+    /// fit; a grid of several columns does not.
+    #[must_use]
+    pub const fn columns(mut self, columns: u16) -> Self {
+        self.chart.options.legend_columns = columns;
+        self
+    }
+
+    /// Sets a header drawn above or below the entries; see
+    /// [`PieChart::legend_title`].
+    #[must_use]
+    pub fn title<T: Into<Title>>(mut self, title: T) -> Self {
+        self.chart = self.chart.legend_title(title);
+        self
+    }
+
+    /// Sets the style of the title.
+    #[must_use]
+    pub fn title_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.chart = self.chart.legend_title_style(style);
+        self
+    }
+
+    /// Sets whether a rule separates the title from the entries.
+    #[must_use]
+    pub const fn separator(mut self, show: bool) -> Self {
+        self.chart.options.legend_separator = show;
+        self
+    }
+
+    /// Draws a percentage bar `width` cells wide in every entry; see
+    /// [`PieChart::legend_bars`].
+    #[must_use]
+    pub const fn bars(mut self, width: u16) -> Self {
+        self.chart.options.legend_bar_width = width;
+        self
+    }
+
+    /// Sets the spacing between entries; see [`LegendConfig`].
+    #[must_use]
+    pub const fn config(mut self, config: LegendConfig) -> Self {
+        self.chart.options.legend_config = config;
+        self
+    }
+
+    /// Sets the order in which a grid fills its cells.
+    #[must_use]
+    pub const fn flow(mut self, flow: LegendFlow) -> Self {
+        self.chart.options.legend_flow = flow;
+        self
+    }
+
+    /// Sets how labels are shortened to fit.
+    #[must_use]
+    pub const fn truncation(mut self, truncation: LegendTruncation) -> Self {
+        self.chart.options.legend_truncation = truncation;
+        self
+    }
+
+    /// Sets the order of the entries.
+    #[must_use]
+    pub const fn order(mut self, order: LegendOrder) -> Self {
+        self.chart.options.legend_order = order;
+        self
+    }
+
+    /// Sets the order of the slices, which also assigns their palette
+    /// colors. Use the same order as the chart the legend belongs to.
+    #[must_use]
+    pub const fn slice_order(mut self, order: SliceOrder) -> Self {
+        self.chart.options.slice_order = order;
+        self
+    }
+
+    /// Sets the palette for slices without an explicit color.
+    #[must_use]
+    pub const fn palette(mut self, palette: Palette<'a>) -> Self {
+        self.chart.options.palette = palette;
+        self
+    }
+
+    /// Sets whether slices without a pattern get a distinct one
+    /// automatically, shown as their marker.
+    #[must_use]
+    pub const fn auto_patterns(mut self, enabled: bool) -> Self {
+        self.chart.options.auto_patterns = enabled;
+        self
+    }
+
+    /// Sets how NaN, infinite and negative values are treated.
+    #[must_use]
+    pub const fn value_policy(mut self, policy: ValuePolicy) -> Self {
+        self.chart.options.value_policy = policy;
+        self
+    }
+
+    /// Sets the style patched onto the entry of the selected slice.
+    #[must_use]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.chart = self.chart.highlight_style(style);
+        self
+    }
+
+    /// Applies the legend options of a theme, as
+    /// [`PieChart::theme`] does.
+    #[must_use]
+    pub fn theme(mut self, theme: &PieChartTheme) -> Self {
+        self.chart = self.chart.theme(theme);
+        self
+    }
+
+    /// Returns the size the legend needs to show every entry, including its
+    /// block.
+    ///
+    /// Horizontal legends are measured as a single row.
+    #[must_use]
+    pub fn preferred_size(&self) -> Size {
+        let chart = &self.chart;
+        let entries = chart.entries();
+        let (width, height) =
+            chart.legend_size(u16::MAX, self.grid_columns(u16::MAX, &entries), &entries);
+        let (block_width, block_height) = chart.options.block.as_ref().map_or((0, 0), |block| {
+            let outer = Rect::new(0, 0, u16::MAX, u16::MAX);
+            let inner = block.inner(outer);
+            (outer.width - inner.width, outer.height - inner.height)
+        });
+        Size::new(
+            width.saturating_add(block_width),
+            height.saturating_add(block_height),
+        )
+    }
+
+    /// Returns the number of grid columns for a vertical legend `width`
+    /// cells wide, or `None` if it is a single scrolling column.
+    fn grid_columns(&self, width: u16, entries: &Entries) -> Option<u16> {
+        let columns = self.chart.legend_grid_columns(width, entries);
+        (self.chart.options.legend_columns != 1 && columns > 1).then_some(columns)
+    }
+}
+
+impl<D: PieDataSource> Widget for PieLegend<'_, D> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl<D: PieDataSource> Widget for &PieLegend<'_, D> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        StatefulWidget::render(self, area, buf, &mut PieChartState::default());
+    }
+}
+
+impl<D: PieDataSource> StatefulWidget for PieLegend<'_, D> {
+    type State = PieChartState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl<D: PieDataSource> StatefulWidget for &PieLegend<'_, D> {
+    type State = PieChartState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let chart = &self.chart;
+        buf.set_style(area, chart.options.style);
+        let inner = if let Some(block) = chart.options.block.as_deref() {
+            let inner_area = block.inner(area);
+            block.render(area, buf);
+            inner_area
+        } else {
+            area
+        };
+        let inner = chart.render_legend_frame(buf, inner);
+        if inner.is_empty() {
+            return;
+        }
+
+        let entries = chart.entries();
+        match chart.options.legend_layout {
+            LegendLayout::Vertical if self.grid_columns(inner.width, &entries).is_some() => {
+                chart.render_grid_legend(buf, inner, &entries, state);
+            }
+            LegendLayout::Vertical => chart.render_vertical_legend(buf, inner, &entries, state),
+            LegendLayout::Horizontal => {
+                chart.render_horizontal_legend(buf, inner, &entries, state);
+            }
+            LegendLayout::Table => chart.render_table_legend(buf, inner, &entries, state),
+        }
+    }
+}
+
 #[cfg(test)]
 #[allow(unnameable_test_items)]
 mod tests {
     use super::*;
     use crate::{debug_format_tests, enum_tests};
+    use ratatui::widgets::Padding;
 
     enum_tests! {
         enum_type: LegendPosition,
@@ -199,6 +1048,27 @@ mod tests {
         debug_test: (legend_layout_debug, LegendLayout::Vertical, "Vertical"),
     }
 
+    enum_tests! {
+        enum_type: LegendFlow,
+        default_test: (legend_flow_default, LegendFlow::RowMajor),
+        clone_test: (legend_flow_clone, LegendFlow::ColumnMajor),
+        debug_test: (legend_flow_debug, LegendFlow::ColumnMajor, "ColumnMajor"),
+    }
+
+    enum_tests! {
+        enum_type: LegendTruncation,
+        default_test: (legend_truncation_default, LegendTruncation::End),
+        clone_test: (legend_truncation_clone, LegendTruncation::Middle),
+        debug_test: (legend_truncation_debug, LegendTruncation::Hide, "Hide"),
+    }
+
+    enum_tests! {
+        enum_type: LegendOrder,
+        default_test: (legend_order_default, LegendOrder::Pie),
+        clone_test: (legend_order_clone, LegendOrder::Insertion),
+        debug_test: (legend_order_debug, LegendOrder::Insertion, "Insertion"),
+    }
+
     enum_tests! {
         enum_type: LegendAlignment,
         default_test: (legend_alignment_default, LegendAlignment::Left),
@@ -223,4 +1093,219 @@ mod tests {
             (legend_alignment_debug_center, LegendAlignment::Center, "Center"),
         ]
     }
+
+    #[test]
+    fn legend_options_round_trip_through_strings() {
+        for position in LegendPosition::iter() {
+            assert_eq!(position.to_string().parse(), Ok(position));
+        }
+        for layout in LegendLayout::iter() {
+            assert_eq!(layout.to_string().parse(), Ok(layout));
+        }
+        for alignment in LegendAlignment::iter() {
+            assert_eq!(alignment.to_string().parse(), Ok(alignment));
+        }
+        for order in LegendOrder::iter() {
+            assert_eq!(order.to_string().parse(), Ok(order));
+        }
+        for flow in LegendFlow::iter() {
+            assert_eq!(flow.to_string().parse(), Ok(flow));
+        }
+        for truncation in LegendTruncation::iter() {
+            assert_eq!(truncation.to_string().parse(), Ok(truncation));
+        }
+    }
+
+    #[test]
+    fn legend_truncation_shortens_labels() {
+        let label = "JavaScript";
+        assert_eq!(LegendTruncation::End.truncate(label, 10), "JavaScript");
+        assert_eq!(LegendTruncation::End.truncate(label, 7), "JavaSc…");
+        assert_eq!(LegendTruncation::Middle.truncate(label, 7), "Jav…ipt");
+        assert_eq!(LegendTruncation::Middle.truncate(label, 6), "Jav…pt");
+        assert_eq!(LegendTruncation::Hide.truncate(label, 7), "");
+        assert_eq!(LegendTruncation::End.truncate(label, 1), "…");
+        assert_eq!(LegendTruncation::End.truncate(label, 0), "");
+    }
+
+    #[test]
+    fn legend_truncation_counts_wide_characters() {
+        assert_eq!(LegendTruncation::End.truncate("日本語です", 6), "日本…");
+        assert_eq!(LegendTruncation::Middle.truncate("日本語です", 6), "日…す");
+        assert_eq!(
+            LegendTruncation::Middle.truncate("日本語です", 7),
+            "日…です"
+        );
+    }
+
+    #[test]
+    fn legend_table_builder() {
+        let table = LegendTable::new()
+            .header(true)
+            .footer(true)
+            .values(false)
+            .precision(2)
+            .style(Modifier::ITALIC);
+        assert!(table.header && table.footer && !table.values);
+        assert_eq!(table.precision, Some(2));
+        assert_eq!(table.style, Style::new().add_modifier(Modifier::ITALIC));
+        assert_eq!(LegendTable::default(), LegendTable::new());
+    }
+
+    #[test]
+    fn legend_config_builder() {
+        let config = LegendConfig::new()
+            .vertical_max_ratio(2)
+            .vertical_min_width(10)
+            .horizontal_max_width(40)
+            .vertical_max_height(6)
+            .spacing(0)
+            .padding(0)
+            .item_spacing(0);
+        assert_eq!(
+            (config.vertical_max_ratio, config.vertical_min_width),
+            (2, 10)
+        );
+        assert_eq!(
+            (config.horizontal_max_width, config.vertical_max_height),
+            (40, 6)
+        );
+        assert_eq!(
+            (config.spacing, config.padding, config.item_pitch()),
+            (0, 0, 1)
+        );
+        assert_eq!(LegendConfig::default().item_pitch(), 2);
+    }
+
+    #[test]
+    fn legend_table_format_value() {
+        assert_eq!(LegendTable::new().format_value(45.0), "45");
+        assert_eq!(LegendTable::new().format_value(2.5), "2.5");
+        assert_eq!(LegendTable::new().precision(1).format_value(45.0), "45.0");
+        assert_eq!(LegendTable::new().precision(0).format_value(2.6), "3");
+    }
+
+    fn legend_slices() -> Vec<PieSlice<'static>> {
+        vec![PieSlice::auto("Rust", 60.0), PieSlice::auto("Go", 40.0)]
+    }
+
+    fn render_lines(legend: &PieLegend) -> Vec<String> {
+        let size = legend.preferred_size();
+        let mut buf = Buffer::empty(Rect::new(0, 0, size.width, size.height));
+        Widget::render(legend, buf.area, &mut buf);
+        buf.content
+            .chunks(usize::from(size.width))
+            .map(|row| row.iter().map(ratatui::buffer::Cell::symbol).collect())
+            .collect()
+    }
+
+    #[test]
+    fn pie_legend_vertical() {
+        let legend = PieLegend::new(legend_slices());
+        assert_eq!(legend.preferred_size(), Size::new(12, 3));
+        assert_eq!(
+            render_lines(&legend),
+            ["■ Rust 60.0%", "            ", "■ Go 40.0%  "]
+        );
+    }
+
+    #[test]
+    fn pie_legend_horizontal_and_table() {
+        let legend = PieLegend::new(legend_slices()).layout(LegendLayout::Horizontal);
+        assert_eq!(render_lines(&legend), ["■ Rust 60.0%  ■ Go 40.0%"]);
+
+        let legend = PieLegend::new(legend_slices())
+            .layout(LegendLayout::Table)
+            .table(LegendTable::new().header(true))
+            .show_percentages(false);
+        assert_eq!(
+            render_lines(&legend),
+            ["  Label  Value", "■ Rust      60", "■ Go        40"]
+        );
+    }
+
+    #[test]
+    fn pie_legend_size_includes_block() {
+        let legend = PieLegend::new(legend_slices())
+            .marker("•")
+            .block(Block::bordered());
+        assert_eq!(legend.preferred_size(), Size::new(14, 5));
+        assert_eq!(render_lines(&legend)[1], "│• Rust 60.0%│");
+        assert_eq!(PieLegend::default().preferred_size(), Size::ZERO);
+
+        let legend = legend.block(Block::bordered().padding(Padding::uniform(60)));
+        assert_eq!(legend.preferred_size(), Size::new(134, 125));
+    }
+
+    #[test]
+    fn pie_legend_from_chart() {
+        let chart = PieChart::new(legend_slices()).legend_position(LegendPosition::Bottom);
+        let legend = PieLegend::from(chart.clone());
+        assert_eq!(legend.preferred_size(), Size::new(32, 1));
+        assert_eq!(render_lines(&legend), ["■ Rust 60.0%    ■ Go 40.0%      "]);
+
+        let legend = PieLegend::from(chart.legend_position(LegendPosition::Left));
+        assert_eq!(legend.preferred_size(), Size::new(12, 3));
+    }
+
+    #[test]
+    fn pie_legend_title() {
+        let legend = PieLegend::new(legend_slices())
+            .title(Title::new("Langs").left())
+            .separator(true);
+        assert_eq!(legend.preferred_size(), Size::new(12, 5));
+        assert_eq!(
+            render_lines(&legend)[..3],
+            ["Langs       ", "────────────", "■ Rust 60.0%"]
+        );
+    }
+
+    #[test]
+    fn pie_legend_highlights_selection() {
+        let legend = PieLegend::new(legend_slices()).highlight_style(Modifier::BOLD);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 3));
+        let mut state = PieChartState::new().with_selected(Some(1));
+        StatefulWidget::render(&legend, buf.area, &mut buf, &mut state);
+        assert!(buf[(2, 2)].modifier.contains(Modifier::BOLD));
+        assert!(!buf[(2, 0)].modifier.contains(Modifier::BOLD));
+    }
+
+    #[test]
+    fn legend_position_parse() {
+        assert_eq!("BOTTOM".parse(), Ok(LegendPosition::Bottom));
+        assert_eq!("top-right".parse(), Ok(LegendPosition::TopRight));
+        assert_eq!(LegendPosition::ALL.len(), 9);
+        let err = "middle".parse::<LegendPosition>().unwrap_err();
+        assert_eq!(err.option(), "LegendPosition");
+        assert_eq!(
+            err.expected(),
+            [
+                "right",
+                "left",
+                "top",
+                "bottom",
+                "top-left",
+                "top-right",
+                "bottom-left",
+                "bottom-right",
+                "auto"
+            ]
+        );
+    }
+
+    #[test]
+    fn legend_position_is_overlay() {
+        let overlays: Vec<_> = LegendPosition::iter()
+            .filter(|position| position.is_overlay())
+            .collect();
+        assert_eq!(
+            overlays,
+            [
+                LegendPosition::TopLeft,
+                LegendPosition::TopRight,
+                LegendPosition::BottomLeft,
+                LegendPosition::BottomRight
+            ]
+        );
+    }
 }
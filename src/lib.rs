@@ -5,11 +5,16 @@
 //! ## Features
 //!
 //! - 🥧 Simple pie chart with customizable slices
-//! - 🎨 Customizable colors for each slice
+//! - 🎨 Customizable colors for each slice, or automatic colors from built-in palettes
 //! - 🔤 Labels and percentages
 //! - 📊 Legend support
 //! - 📦 Optional block wrapper
 //! - ✨ Custom symbols for pie chart and legend
+//! - 🏁 Fill patterns that keep slices distinguishable without color
+//! - 🖌️ Reusable themes for consistent styling across charts
+//! - 🗂️ Owned chart configuration, serializable with the `serde` feature
+//! - 🔌 Pluggable data sources for charting your own types without copying
+//! - 🎯 Stateful rendering with slice selection and a scrolling legend
 //! - ⚡ Zero-cost abstractions
 //!
 //! ## Examples
@@ -87,25 +92,54 @@
 #![warn(clippy::pedantic)]
 #![allow(clippy::module_name_repetitions)]
 
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
 use std::f64::consts::PI;
+use std::hash::BuildHasher;
+use std::ops::Deref;
 
 use ratatui::buffer::Buffer;
-use ratatui::layout::Rect;
-use ratatui::style::{Color, Style, Styled};
+use ratatui::layout::{HorizontalAlignment, Margin, Rect, VerticalAlignment};
+use ratatui::style::{Color, Modifier, Style, Styled};
+use ratatui::symbols::block;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Widget};
+use ratatui::widgets::{Block, Clear, StatefulWidget, Widget};
+use unicode_width::UnicodeWidthStr;
 
 pub mod border_style;
+pub mod config;
+pub mod error;
 pub mod legend;
 #[macro_use]
 pub mod macros;
+pub mod palette;
+pub mod pattern;
+pub mod placeholder;
+pub mod source;
+pub mod state;
 pub mod symbols;
+pub mod theme;
 pub mod title;
 
 // Re-export commonly used types from submodules for convenience
-pub use legend::{LegendAlignment, LegendLayout, LegendPosition};
+use error::ValueErrorKind;
+pub use error::{ParseOptionError, SliceValueError};
+pub use legend::{
+    LegendAlignment, LegendConfig, LegendFlow, LegendLayout, LegendOrder, LegendPosition,
+    LegendTable, LegendTruncation, PieLegend,
+};
+pub use palette::Palette;
+pub use pattern::FillPattern;
+pub use placeholder::Placeholder;
+pub use source::PieDataSource;
+pub use state::PieChartState;
+pub use theme::PieChartTheme;
 pub use title::{BlockExt, TitleAlignment, TitlePosition, TitleStyle};
 
+use border_style::BorderStyle;
+use title::Title;
+
 /// Rendering resolution mode for pie charts.
 ///
 /// Different resolution modes provide varying levels of detail by using
@@ -128,6 +162,11 @@ pub use title::{BlockExt, TitleAlignment, TitlePosition, TitleStyle};
 ///     .resolution(Resolution::Braille);
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum Resolution {
     /// Standard resolution using full characters (1 dot per cell).
     ///
@@ -142,9 +181,189 @@ pub enum Resolution {
     Braille,
 }
 
+crate::macros::option::option_enum! {
+    Resolution {
+        Standard => "standard",
+        Braille => "braille",
+    }
+}
+
+/// How a chart treats slices whose value is NaN, infinite, or negative.
+///
+/// The policy applies to the pie and the legend alike, so both always show
+/// the same set of slices. Use [`PieChart::validate`] to find out which
+/// slice is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use tui_piechart::{PieChart, PieSlice, ValuePolicy};
+///
+/// let slices = vec![
+///     PieSlice::auto("Rust", 45.0),
+///     PieSlice::auto("Unknown", f64::NAN),
+/// ];
+///
+/// // Leave the invalid slice out of the pie and the legend
+/// let piechart = PieChart::new(slices).value_policy(ValuePolicy::Skip);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum ValuePolicy {
+    /// Invalid values count as zero: the slice takes no space in the pie and
+    /// is listed at 0% in the legend (default).
+    #[default]
+    Clamp,
+
+    /// Invalid slices are left out of the pie and the legend.
+    Skip,
+
+    /// A chart with any invalid slice is drawn as if it had no slices: its
+    /// block and, if one is set, its [`Placeholder`].
+    Error,
+}
+
+crate::macros::option::option_enum! {
+    ValuePolicy {
+        Clamp => "clamp",
+        Skip => "skip",
+        Error => "error",
+    }
+}
+
+/// Order in which slices are drawn.
+///
+/// Slices are drawn clockwise from 12 o'clock, so the first slice in this
+/// order starts at the top of the pie. Sorting is stable: slices that compare
+/// equal keep the order they were added in. Palette colors and automatic
+/// patterns follow each slice's index in the data rather than its rank, so a
+/// slice keeps its color when live data reorders it.
+///
+/// # Examples
+///
+/// ```
+/// use tui_piechart::{PieChart, PieSlice, SliceOrder};
+///
+/// let slices = vec![
+///     PieSlice::auto("Go", 30.0),
+///     PieSlice::auto("Rust", 45.0),
+///     PieSlice::auto("Python", 25.0),
+/// ];
+///
+/// // Largest slice first
+/// let chart = PieChart::new(slices).slice_order(SliceOrder::ValueDescending);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum SliceOrder {
+    /// The order the slices were added in (default)
+    #[default]
+    Insertion,
+
+    /// Largest value first
+    ValueDescending,
+
+    /// Smallest value first
+    ValueAscending,
+
+    /// Alphabetical by label
+    Label,
+}
+
+crate::macros::option::option_enum! {
+    SliceOrder {
+        Insertion => "insertion",
+        ValueDescending => "value-descending",
+        ValueAscending => "value-ascending",
+        Label => "label",
+    }
+}
+
+/// Size of the pie within its area.
+///
+/// The radius is counted in columns. Terminal cells are about twice as tall
+/// as they are wide, so a pie of radius `n` spans `2n + 1` columns and
+/// `n + 1` rows. Giving every chart of a dashboard the same fixed radius
+/// makes their pies directly comparable.
+///
+/// # Examples
+///
+/// ```
+/// use tui_piechart::{PieChart, PieRadius, PieSlice};
+///
+/// let slices = vec![PieSlice::auto("Rust", 45.0), PieSlice::auto("Go", 30.0)];
+///
+/// // As large as fits, but no larger than 8 columns
+/// let chart = PieChart::new(slices).radius(PieRadius::Max(8));
+///
+/// // The same radius, spelled as in a config file or command line
+/// assert_eq!("max:8".parse(), Ok(PieRadius::Max(8)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum PieRadius {
+    /// The largest pie that fits in the area (default)
+    #[default]
+    Fit,
+
+    /// Always this radius; a pie too large for the area is clipped
+    Fixed(u16),
+
+    /// The largest pie that fits, up to this radius
+    Max(u16),
+}
+
+/// Formats the radius as `fit`, `fixed:<radius>` or `max:<radius>`, the
+/// spellings [`FromStr`](std::str::FromStr) accepts.
+impl std::fmt::Display for PieRadius {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fit => f.pad("fit"),
+            Self::Fixed(radius) => f.pad(&format!("fixed:{radius}")),
+            Self::Max(radius) => f.pad(&format!("max:{radius}")),
+        }
+    }
+}
+
+impl std::str::FromStr for PieRadius {
+    type Err = ParseOptionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const NAMES: &[&str] = &["fit", "fixed:<radius>", "max:<radius>"];
+        let error = || ParseOptionError::new("PieRadius", s, NAMES);
+        let Some((name, radius)) = s.split_once(':') else {
+            return crate::macros::option::name_matches(s, "fit")
+                .then_some(Self::Fit)
+                .ok_or_else(error);
+        };
+        let radius = radius.trim().parse().map_err(|_| error())?;
+        if crate::macros::option::name_matches(name, "fixed") {
+            Ok(Self::Fixed(radius))
+        } else if crate::macros::option::name_matches(name, "max") {
+            Ok(Self::Max(radius))
+        } else {
+            Err(error())
+        }
+    }
+}
+
 /// A slice of the pie chart representing a portion of data.
 ///
-/// Each slice has a label, a value, and a color.
+/// Each slice has a label, a value, and an optional color. Slices without a
+/// color are colored from the chart's [`Palette`]. A slice can also carry a
+/// full [`Style`] that is applied to its pie cells and its legend entry.
 ///
 /// # Examples
 ///
@@ -153,6 +372,9 @@ pub enum Resolution {
 /// use tui_piechart::PieSlice;
 ///
 /// let slice = PieSlice::new("Rust", 45.0, Color::Red);
+///
+/// // Let the chart pick the color
+/// let slice = PieSlice::auto("Go", 30.0);
 /// ```
 #[derive(Debug, Clone, PartialEq)]
 pub struct PieSlice<'a> {
@@ -160,8 +382,18 @@ pub struct PieSlice<'a> {
     label: &'a str,
     /// The value of this slice (will be converted to percentage)
     value: f64,
-    /// The color of this slice
-    color: Color,
+    /// The color of this slice, or `None` to use the chart palette
+    color: Option<Color>,
+    /// The fill pattern of this slice, or `None` to use the chart default
+    pattern: Option<FillPattern>,
+    /// Style patched over the slice color for pie cells and the legend entry
+    style: Style,
+    /// Style patched over `style` for the legend entry only
+    legend_style: Option<Style>,
+    /// Fill character overriding the chart's pie character
+    pie_char: Option<char>,
+    /// Legend marker overriding the chart's legend marker
+    legend_marker: Option<&'a str>,
 }
 
 impl<'a> PieSlice<'a> {
@@ -180,7 +412,41 @@ impl<'a> PieSlice<'a> {
         Self {
             label,
             value,
-            color,
+            color: Some(color),
+            pattern: None,
+            style: Style::new(),
+            legend_style: None,
+            pie_char: None,
+            legend_marker: None,
+        }
+    }
+
+    /// Creates a new pie slice without an explicit color.
+    ///
+    /// The slice is colored from the chart's [`Palette`] when rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::{PieChart, PieSlice, Palette};
+    ///
+    /// let chart = PieChart::new(vec![
+    ///     PieSlice::auto("Rust", 45.0),
+    ///     PieSlice::auto("Go", 30.0),
+    /// ])
+    /// .palette(Palette::Tableau);
+    /// ```
+    #[must_use]
+    pub const fn auto(label: &'a str, value: f64) -> Self {
+        Self {
+            label,
+            value,
+            color: None,
+            pattern: None,
+            style: Style::new(),
+            legend_style: None,
+            pie_char: None,
+            legend_marker: None,
         }
     }
 
@@ -197,9 +463,164 @@ impl<'a> PieSlice<'a> {
     }
 
     /// Returns the color of this slice.
+    ///
+    /// Slices created with [`auto`](Self::auto) return [`Color::Reset`]; their
+    /// actual color is chosen by the chart's palette.
     #[must_use]
     pub const fn color(&self) -> Color {
-        self.color
+        match self.color {
+            Some(color) => color,
+            None => Color::Reset,
+        }
+    }
+
+    /// Returns `true` if this slice was given an explicit color.
+    #[must_use]
+    pub const fn has_color(&self) -> bool {
+        self.color.is_some()
+    }
+
+    /// Sets the fill pattern of this slice.
+    ///
+    /// The pattern overrides any pattern assigned by
+    /// [`PieChart::auto_patterns`], and its glyph is also used as the slice's
+    /// legend marker.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tui_piechart::{FillPattern, PieSlice};
+    ///
+    /// let slice = PieSlice::new("Unknown", 5.0, Color::Gray)
+    ///     .pattern(FillPattern::LightShade);
+    /// ```
+    #[must_use]
+    pub const fn pattern(mut self, pattern: FillPattern) -> Self {
+        self.pattern = Some(pattern);
+        self
+    }
+
+    /// Returns the fill pattern set on this slice, if any.
+    #[must_use]
+    pub const fn fill_pattern(&self) -> Option<FillPattern> {
+        self.pattern
+    }
+
+    /// Sets the style of this slice.
+    ///
+    /// The style is patched over the slice color and applied to both the pie
+    /// cells and the legend entry of this slice. A foreground color in the
+    /// style takes precedence over the slice color.
+    ///
+    /// `PieSlice` also implements [`Styled`], so the [`Stylize`] shorthands
+    /// work as well.
+    ///
+    /// [`Stylize`]: ratatui::style::Stylize
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Modifier, Style, Stylize};
+    /// use tui_piechart::PieSlice;
+    ///
+    /// let slice = PieSlice::new("Errors", 5.0, Color::Red)
+    ///     .style(Style::default().bg(Color::Black).add_modifier(Modifier::BOLD));
+    ///
+    /// // Or with Stylize shorthands
+    /// let slice = PieSlice::new("Errors", 5.0, Color::Red).bold().on_black();
+    /// ```
+    #[must_use]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets an additional style for the legend entry of this slice.
+    ///
+    /// The legend style is patched over the slice style, so it only needs to
+    /// contain the differences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Modifier, Style};
+    /// use tui_piechart::PieSlice;
+    ///
+    /// // Emphasize the legend entry without changing the pie cells
+    /// let slice = PieSlice::new("Rust", 45.0, Color::Red)
+    ///     .legend_style(Style::default().add_modifier(Modifier::REVERSED));
+    /// ```
+    #[must_use]
+    pub fn legend_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.legend_style = Some(style.into());
+        self
+    }
+
+    /// Sets the character used to draw this slice, overriding the chart's
+    /// [`pie_char`](PieChart::pie_char) and any fill pattern glyph.
+    ///
+    /// Only affects [`Resolution::Standard`]; braille rendering always uses
+    /// braille dots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tui_piechart::{symbols, PieSlice};
+    ///
+    /// let slice = PieSlice::new("Errors", 5.0, Color::Red)
+    ///     .pie_char(symbols::PIE_CHAR_CROSS);
+    /// ```
+    #[must_use]
+    pub const fn pie_char(mut self, c: char) -> Self {
+        self.pie_char = Some(c);
+        self
+    }
+
+    /// Sets the legend marker of this slice, overriding the chart's
+    /// [`legend_marker`](PieChart::legend_marker) and any fill pattern glyph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tui_piechart::{symbols, PieSlice};
+    ///
+    /// let slice = PieSlice::new("Errors", 5.0, Color::Red)
+    ///     .pie_char(symbols::PIE_CHAR_CROSS)
+    ///     .legend_marker(symbols::LEGEND_MARKER_CROSS);
+    /// ```
+    #[must_use]
+    pub const fn legend_marker(mut self, marker: &'a str) -> Self {
+        self.legend_marker = Some(marker);
+        self
+    }
+}
+
+impl<'a> From<(&'a str, f64)> for PieSlice<'a> {
+    /// Creates a slice colored from the chart's palette, like [`PieSlice::auto`].
+    fn from((label, value): (&'a str, f64)) -> Self {
+        Self::auto(label, value)
+    }
+}
+
+impl<'a> From<(&'a str, f64, Color)> for PieSlice<'a> {
+    fn from((label, value, color): (&'a str, f64, Color)) -> Self {
+        Self::new(label, value, color)
+    }
+}
+
+impl Styled for PieSlice<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(mut self, style: S) -> Self::Item {
+        self.style = style.into();
+        self
     }
 }
 
@@ -222,11 +643,47 @@ impl<'a> PieSlice<'a> {
 /// let piechart = PieChart::new(slices);
 /// ```
 #[derive(Debug, Clone, PartialEq)]
-pub struct PieChart<'a> {
-    /// The slices of the pie chart
-    slices: Vec<PieSlice<'a>>,
+pub struct PieChart<'a, D = Vec<PieSlice<'a>>> {
+    /// The slice data of the pie chart
+    slices: D,
+    /// Display options, shared by every data source
+    options: ChartOptions<'a>,
+}
+
+impl Default for PieChart<'_> {
+    /// Returns a default `PieChart` widget.
+    ///
+    /// The default widget has:
+    /// - No slices
+    /// - No block
+    /// - Default style
+    /// - Legend shown
+    /// - Percentages shown
+    /// - Default pie character (●)
+    /// - Default legend marker (■)
+    /// - Categorical palette for uncolored slices
+    /// - Invalid values clamped to zero
+    /// - No placeholder for empty charts
+    /// - Slices drawn in insertion order
+    fn default() -> Self {
+        Self {
+            slices: Vec::new(),
+            options: ChartOptions::default(),
+        }
+    }
+}
+
+/// The display options of a [`PieChart`], kept apart from its data source so
+/// that swapping or borrowing the source moves them as one value.
+///
+/// Every option but the block and the legend title is `Copy`. Those two are
+/// held as [`MaybeBorrowed`], so that a [borrowing copy](PieChart::by_ref) of
+/// the chart costs no allocation.
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::struct_excessive_bools)]
+struct ChartOptions<'a> {
     /// Optional block to wrap the pie chart
-    block: Option<Block<'a>>,
+    block: Option<MaybeBorrowed<'a, Block<'a>>>,
     /// Base style for the entire widget
     style: Style,
     /// Whether to show the legend
@@ -239,28 +696,59 @@ pub struct PieChart<'a> {
     legend_marker: &'a str,
     /// Resolution mode for rendering
     resolution: Resolution,
+    /// Size of the pie
+    radius: PieRadius,
+    /// Horizontal position of the pie in its area
+    pie_alignment: HorizontalAlignment,
+    /// Vertical position of the pie in its area
+    pie_vertical_alignment: VerticalAlignment,
     /// Position of the legend
     legend_position: LegendPosition,
     /// Layout of the legend
     legend_layout: LegendLayout,
     /// Alignment of legend items
     legend_alignment: LegendAlignment,
+    /// Palette used for slices without an explicit color
+    palette: Palette<'a>,
+    /// Whether slices without a pattern get a distinct one automatically
+    auto_patterns: bool,
+    /// How NaN, infinite and negative values are treated
+    value_policy: ValuePolicy,
+    /// What to draw when there is nothing to draw
+    placeholder: Option<Placeholder<'a>>,
+    /// Order in which slices are drawn
+    slice_order: SliceOrder,
+    /// Order of legend items
+    legend_order: LegendOrder,
+    /// Options for the table legend layout
+    legend_table: LegendTable,
+    /// Number of grid legend columns, or `0` to fit as many as possible
+    legend_columns: u16,
+    /// Order in which the grid legend fills its cells
+    legend_flow: LegendFlow,
+    /// Style patched onto the legend entry of the selected slice
+    highlight_style: Style,
+    /// Size and spacing of the legend
+    legend_config: LegendConfig,
+    /// Header drawn above or below the legend entries
+    legend_title: Option<MaybeBorrowed<'a, Title>>,
+    /// Style of the legend title
+    legend_title_style: Style,
+    /// Whether a rule separates the legend title from the entries
+    legend_separator: bool,
+    /// Border drawn around the legend area
+    legend_border: Option<BorderStyle>,
+    /// Width of the bar drawn in each legend entry, or `0` for no bars
+    legend_bar_width: u16,
+    /// How labels are shortened when an entry is wider than the legend
+    legend_truncation: LegendTruncation,
+    /// Style the legend area is filled with before the entries are drawn
+    legend_background: Option<Style>,
 }
 
-impl Default for PieChart<'_> {
-    /// Returns a default `PieChart` widget.
-    ///
-    /// The default widget has:
-    /// - No slices
-    /// - No block
-    /// - Default style
-    /// - Legend shown
-    /// - Percentages shown
-    /// - Default pie character (●)
-    /// - Default legend marker (■)
+impl Default for ChartOptions<'_> {
     fn default() -> Self {
         Self {
-            slices: Vec::new(),
             block: None,
             style: Style::default(),
             show_legend: true,
@@ -268,13 +756,103 @@ impl Default for PieChart<'_> {
             pie_char: symbols::PIE_CHAR,
             legend_marker: symbols::LEGEND_MARKER,
             resolution: Resolution::default(),
+            radius: PieRadius::default(),
+            pie_alignment: HorizontalAlignment::Center,
+            pie_vertical_alignment: VerticalAlignment::Center,
             legend_position: LegendPosition::default(),
             legend_layout: LegendLayout::default(),
             legend_alignment: LegendAlignment::default(),
+            palette: Palette::default(),
+            auto_patterns: false,
+            value_policy: ValuePolicy::default(),
+            placeholder: None,
+            slice_order: SliceOrder::default(),
+            legend_order: LegendOrder::default(),
+            legend_table: LegendTable::default(),
+            legend_columns: 0,
+            legend_flow: LegendFlow::default(),
+            highlight_style: Style::new().add_modifier(Modifier::REVERSED),
+            legend_config: LegendConfig::new(),
+            legend_title: None,
+            legend_title_style: Style::new().add_modifier(Modifier::BOLD),
+            legend_separator: false,
+            legend_border: None,
+            legend_bar_width: 0,
+            legend_truncation: LegendTruncation::default(),
+            legend_background: None,
+        }
+    }
+}
+
+/// A value a [`PieChart`] either owns or borrows from the chart it was copied
+/// from. Unlike [`Cow`], it keeps the chart covariant in its lifetime.
+#[derive(Debug)]
+enum MaybeBorrowed<'a, T> {
+    Owned(T),
+    Borrowed(&'a T),
+}
+
+impl<T> Deref for MaybeBorrowed<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match self {
+            Self::Owned(value) => value,
+            Self::Borrowed(value) => value,
+        }
+    }
+}
+
+impl<T: Clone> Clone for MaybeBorrowed<'_, T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Owned(value) => Self::Owned(value.clone()),
+            Self::Borrowed(value) => Self::Borrowed(value),
         }
     }
 }
 
+impl<T: PartialEq> PartialEq for MaybeBorrowed<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<'a> FromIterator<PieSlice<'a>> for PieChart<'a> {
+    fn from_iter<I: IntoIterator<Item = PieSlice<'a>>>(slices: I) -> Self {
+        Self::new(slices.into_iter().collect())
+    }
+}
+
+impl<'a> From<&[(&'a str, f64, Color)]> for PieChart<'a> {
+    /// Creates a chart from `(label, value, color)` tuples.
+    fn from(slices: &[(&'a str, f64, Color)]) -> Self {
+        slices.iter().copied().map(PieSlice::from).collect()
+    }
+}
+
+impl<'a> From<&'a BTreeMap<String, f64>> for PieChart<'a> {
+    /// Creates a chart with one palette-colored slice per entry, in key order.
+    fn from(values: &'a BTreeMap<String, f64>) -> Self {
+        Self::from_values(values.iter().map(|(label, &value)| (label.as_str(), value)))
+    }
+}
+
+impl<'a, S: BuildHasher> From<&'a HashMap<String, f64, S>> for PieChart<'a> {
+    /// Creates a chart with one palette-colored slice per entry.
+    ///
+    /// Entries are sorted by key, so the chart does not depend on the map's
+    /// iteration order.
+    fn from(values: &'a HashMap<String, f64, S>) -> Self {
+        let mut entries: Vec<_> = values
+            .iter()
+            .map(|(label, &value)| (label.as_str(), value))
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+        Self::from_values(entries)
+    }
+}
+
 impl<'a> PieChart<'a> {
     /// Creates a new `PieChart` with the given slices.
     ///
@@ -298,41 +876,151 @@ impl<'a> PieChart<'a> {
         }
     }
 
-    /// Sets the slices of the pie chart.
+    /// Creates a new `PieChart` from `(label, value)` pairs, colored from the
+    /// chart's palette.
     ///
     /// # Examples
     ///
     /// ```
-    /// use ratatui::style::Color;
-    /// use tui_piechart::{PieChart, PieSlice};
+    /// use tui_piechart::PieChart;
     ///
-    /// let slices = vec![
-    ///     PieSlice::new("Rust", 45.0, Color::Red),
-    /// ];
-    /// let piechart = PieChart::default().slices(slices);
+    /// let counts = [("Rust", 45.0), ("Go", 30.0), ("Python", 25.0)];
+    /// let piechart = PieChart::from_values(counts);
     /// ```
     #[must_use]
-    pub fn slices(mut self, slices: Vec<PieSlice<'a>>) -> Self {
-        self.slices = slices;
-        self
+    pub fn from_values<I>(values: I) -> Self
+    where
+        I: IntoIterator<Item = (&'a str, f64)>,
+    {
+        values.into_iter().map(PieSlice::from).collect()
     }
 
-    /// Wraps the pie chart with the given block.
+    /// Creates a new `PieChart`, rejecting slices with NaN, infinite, or
+    /// negative values.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SliceValueError`] naming the first invalid slice.
     ///
     /// # Examples
     ///
     /// ```
-    /// use ratatui::style::Color;
-    /// use ratatui::widgets::Block;
     /// use tui_piechart::{PieChart, PieSlice};
     ///
-    /// let slices = vec![PieSlice::new("Rust", 45.0, Color::Red)];
+    /// assert!(PieChart::try_new(vec![PieSlice::auto("Rust", 45.0)]).is_ok());
+    /// assert!(PieChart::try_new(vec![PieSlice::auto("Rust", -1.0)]).is_err());
+    /// ```
+    pub fn try_new(slices: Vec<PieSlice<'a>>) -> Result<Self, SliceValueError> {
+        let chart = Self::new(slices);
+        chart.validate()?;
+        Ok(chart)
+    }
+
+    /// Sets the slices of the pie chart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tui_piechart::{PieChart, PieSlice};
+    ///
+    /// let slices = vec![
+    ///     PieSlice::new("Rust", 45.0, Color::Red),
+    /// ];
+    /// let piechart = PieChart::default().slices(slices);
+    /// ```
+    #[must_use]
+    pub fn slices(mut self, slices: Vec<PieSlice<'a>>) -> Self {
+        self.slices = slices;
+        self
+    }
+}
+
+impl<'a, D: PieDataSource> PieChart<'a, D> {
+    /// Creates a new `PieChart` that reads its slices from `source`.
+    ///
+    /// Nothing is copied: the chart reads labels and values through the
+    /// [`PieDataSource`] trait while rendering. See the [`source`] module for
+    /// charting your own types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::{PieChart, PieSlice};
+    ///
+    /// let slices = [PieSlice::auto("Rust", 45.0), PieSlice::auto("Go", 30.0)];
+    /// let piechart = PieChart::from_source(&slices[..]);
+    /// ```
+    #[must_use]
+    pub fn from_source(source: D) -> Self {
+        <PieChart<'a>>::default().data_source(source)
+    }
+
+    /// Replaces the slice data with `source`, keeping every other option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::{PieChart, PieSlice};
+    ///
+    /// let slices = vec![PieSlice::auto("Rust", 45.0)];
+    /// let piechart = PieChart::default().show_legend(false).data_source(&slices);
+    /// ```
+    #[must_use]
+    pub fn data_source<S: PieDataSource>(self, source: S) -> PieChart<'a, S> {
+        PieChart {
+            slices: source,
+            options: self.options,
+        }
+    }
+
+    /// Checks that every slice value is finite and not negative.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SliceValueError`] naming the first invalid slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::error::ValueErrorKind;
+    /// use tui_piechart::{PieChart, PieSlice};
+    ///
+    /// let piechart = PieChart::new(vec![
+    ///     PieSlice::auto("Rust", 45.0),
+    ///     PieSlice::auto("Go", f64::INFINITY),
+    /// ]);
+    /// let err = piechart.validate().unwrap_err();
+    /// assert_eq!(err.label(), "Go");
+    /// assert_eq!(err.kind(), ValueErrorKind::Infinite);
+    /// ```
+    pub fn validate(&self) -> Result<(), SliceValueError> {
+        for index in 0..self.slices.len() {
+            let value = self.slices.value(index);
+            if let Some(kind) = ValueErrorKind::of(value) {
+                let label = self.slices.label(index);
+                return Err(SliceValueError::new(index, label, value, kind));
+            }
+        }
+        Ok(())
+    }
+
+    /// Wraps the pie chart with the given block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use ratatui::widgets::Block;
+    /// use tui_piechart::{PieChart, PieSlice};
+    ///
+    /// let slices = vec![PieSlice::new("Rust", 45.0, Color::Red)];
     /// let piechart = PieChart::new(slices)
     ///     .block(Block::bordered().title("Statistics"));
     /// ```
     #[must_use]
     pub fn block(mut self, block: Block<'a>) -> Self {
-        self.block = Some(block);
+        self.options.block = Some(MaybeBorrowed::Owned(block));
         self
     }
 
@@ -349,7 +1037,7 @@ impl<'a> PieChart<'a> {
     /// ```
     #[must_use]
     pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
-        self.style = style.into();
+        self.options.style = style.into();
         self
     }
 
@@ -364,7 +1052,7 @@ impl<'a> PieChart<'a> {
     /// ```
     #[must_use]
     pub const fn show_legend(mut self, show: bool) -> Self {
-        self.show_legend = show;
+        self.options.show_legend = show;
         self
     }
 
@@ -379,7 +1067,7 @@ impl<'a> PieChart<'a> {
     /// ```
     #[must_use]
     pub const fn show_percentages(mut self, show: bool) -> Self {
-        self.show_percentages = show;
+        self.options.show_percentages = show;
         self
     }
 
@@ -407,7 +1095,7 @@ impl<'a> PieChart<'a> {
     /// ```
     #[must_use]
     pub const fn pie_char(mut self, c: char) -> Self {
-        self.pie_char = c;
+        self.options.pie_char = c;
         self
     }
 
@@ -442,7 +1130,7 @@ impl<'a> PieChart<'a> {
     /// ```
     #[must_use]
     pub const fn legend_marker(mut self, marker: &'a str) -> Self {
-        self.legend_marker = marker;
+        self.options.legend_marker = marker;
         self
     }
 
@@ -462,7 +1150,47 @@ impl<'a> PieChart<'a> {
     /// ```
     #[must_use]
     pub const fn resolution(mut self, resolution: Resolution) -> Self {
-        self.resolution = resolution;
+        self.options.resolution = resolution;
+        self
+    }
+
+    /// Sets the radius of the pie; see [`PieRadius`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::{PieChart, PieRadius};
+    ///
+    /// let piechart = PieChart::default().radius(PieRadius::Fixed(6));
+    /// ```
+    #[must_use]
+    pub const fn radius(mut self, radius: PieRadius) -> Self {
+        self.options.radius = radius;
+        self
+    }
+
+    /// Sets where the pie sits in its area when the area is larger than the
+    /// pie. The pie is centered by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::layout::{HorizontalAlignment, VerticalAlignment};
+    /// use tui_piechart::{PieChart, PieRadius};
+    ///
+    /// // A small pie in the top left corner
+    /// let piechart = PieChart::default()
+    ///     .radius(PieRadius::Fixed(4))
+    ///     .pie_alignment(HorizontalAlignment::Left, VerticalAlignment::Top);
+    /// ```
+    #[must_use]
+    pub const fn pie_alignment(
+        mut self,
+        horizontal: HorizontalAlignment,
+        vertical: VerticalAlignment,
+    ) -> Self {
+        self.options.pie_alignment = horizontal;
+        self.options.pie_vertical_alignment = vertical;
         self
     }
 
@@ -480,7 +1208,7 @@ impl<'a> PieChart<'a> {
     /// ```
     #[must_use]
     pub const fn high_resolution(mut self, enabled: bool) -> Self {
-        self.resolution = if enabled {
+        self.options.resolution = if enabled {
             Resolution::Braille
         } else {
             Resolution::Standard
@@ -500,7 +1228,7 @@ impl<'a> PieChart<'a> {
     /// ```
     #[must_use]
     pub const fn legend_position(mut self, position: LegendPosition) -> Self {
-        self.legend_position = position;
+        self.options.legend_position = position;
         self
     }
 
@@ -521,7 +1249,7 @@ impl<'a> PieChart<'a> {
     /// ```
     #[must_use]
     pub const fn legend_layout(mut self, layout: LegendLayout) -> Self {
-        self.legend_layout = layout;
+        self.options.legend_layout = layout;
         self
     }
 
@@ -542,161 +1270,694 @@ impl<'a> PieChart<'a> {
     /// ```
     #[must_use]
     pub const fn legend_alignment(mut self, alignment: LegendAlignment) -> Self {
-        self.legend_alignment = alignment;
+        self.options.legend_alignment = alignment;
         self
     }
 
-    fn total_value(&self) -> f64 {
-        self.slices.iter().map(|s| s.value).sum()
+    /// Sets the palette used to color slices without an explicit color.
+    ///
+    /// Slices created with [`PieSlice::auto`] take the palette color at their
+    /// position in the chart. Slices with an explicit color are unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::{PieChart, PieSlice, Palette};
+    ///
+    /// let piechart = PieChart::new(vec![
+    ///     PieSlice::auto("Rust", 45.0),
+    ///     PieSlice::auto("Go", 30.0),
+    /// ])
+    /// .palette(Palette::OkabeIto);
+    /// ```
+    #[must_use]
+    pub const fn palette(mut self, palette: Palette<'a>) -> Self {
+        self.options.palette = palette;
+        self
     }
 
-    /// Computes the percentage a value represents of a total.
+    /// Sets whether slices without an explicit pattern get a distinct
+    /// [`FillPattern`] automatically.
     ///
-    /// Returns `0.0` when `total` is not strictly positive, which keeps the
-    /// arithmetic safe for empty charts and all-zero data sets.
-    fn value_percent(value: f64, total: f64) -> f64 {
-        if total > 0.0 {
-            (value / total) * 100.0
-        } else {
-            0.0
-        }
+    /// Patterns are assigned by slice position from [`FillPattern::DISTINCT`],
+    /// so the chart stays readable without color. The legend marker of each
+    /// entry shows its slice's pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::{PieChart, PieSlice};
+    ///
+    /// let piechart = PieChart::new(vec![
+    ///     PieSlice::auto("Rust", 45.0),
+    ///     PieSlice::auto("Go", 30.0),
+    /// ])
+    /// .auto_patterns(true);
+    /// ```
+    #[must_use]
+    pub const fn auto_patterns(mut self, enabled: bool) -> Self {
+        self.options.auto_patterns = enabled;
+        self
     }
 
-    /// Calculates the percentage for a given slice.
-    fn percentage(&self, slice: &PieSlice) -> f64 {
-        Self::value_percent(slice.value, self.total_value())
+    /// Sets how slices with NaN, infinite, or negative values are treated.
+    ///
+    /// See [`ValuePolicy`] for the available policies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::{PieChart, PieSlice, ValuePolicy};
+    ///
+    /// let piechart = PieChart::new(vec![PieSlice::auto("Rust", 45.0)])
+    ///     .value_policy(ValuePolicy::Error);
+    /// ```
+    #[must_use]
+    pub const fn value_policy(mut self, policy: ValuePolicy) -> Self {
+        self.options.value_policy = policy;
+        self
     }
-}
-
-impl Styled for PieChart<'_> {
-    type Item = Self;
 
-    fn style(&self) -> Style {
-        self.style
+    /// Sets what to draw when the chart has no slices or its values add up
+    /// to zero.
+    ///
+    /// Without a placeholder such a chart draws nothing but its block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tui_piechart::{PieChart, Placeholder};
+    ///
+    /// let piechart = PieChart::default()
+    ///     .placeholder(Placeholder::new("No data").ring(Color::DarkGray));
+    /// ```
+    #[must_use]
+    pub const fn placeholder(mut self, placeholder: Placeholder<'a>) -> Self {
+        self.options.placeholder = Some(placeholder);
+        self
     }
 
-    fn set_style<S: Into<Style>>(mut self, style: S) -> Self::Item {
-        self.style = style.into();
+    /// Sets the order in which slices are drawn.
+    ///
+    /// The legend follows the same order unless
+    /// [`legend_order`](Self::legend_order) says otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::{PieChart, PieSlice, SliceOrder};
+    ///
+    /// let piechart = PieChart::new(vec![
+    ///     PieSlice::auto("Go", 30.0),
+    ///     PieSlice::auto("Rust", 45.0),
+    /// ])
+    /// .slice_order(SliceOrder::Label);
+    /// ```
+    #[must_use]
+    pub const fn slice_order(mut self, order: SliceOrder) -> Self {
+        self.options.slice_order = order;
         self
     }
-}
 
-impl Widget for PieChart<'_> {
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        Widget::render(&self, area, buf);
+    /// Sets whether the legend follows the slice order or keeps the order the
+    /// slices were added in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::{LegendOrder, PieChart, PieSlice, SliceOrder};
+    ///
+    /// let piechart = PieChart::new(vec![PieSlice::auto("Rust", 45.0)])
+    ///     .slice_order(SliceOrder::ValueDescending)
+    ///     .legend_order(LegendOrder::Insertion);
+    /// ```
+    #[must_use]
+    pub const fn legend_order(mut self, order: LegendOrder) -> Self {
+        self.options.legend_order = order;
+        self
     }
-}
 
-impl Widget for &PieChart<'_> {
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        buf.set_style(area, self.style);
-        let inner = if let Some(ref block) = self.block {
-            let inner_area = block.inner(area);
-            block.render(area, buf);
-            inner_area
-        } else {
-            area
-        };
-        self.render_piechart(inner, buf);
+    /// Sets the options of the [`LegendLayout::Table`] legend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::{LegendLayout, LegendTable, PieChart, PieSlice};
+    ///
+    /// let piechart = PieChart::new(vec![PieSlice::auto("Rust", 45.0)])
+    ///     .legend_layout(LegendLayout::Table)
+    ///     .legend_table(LegendTable::new().header(true).footer(true));
+    /// ```
+    #[must_use]
+    pub const fn legend_table(mut self, table: LegendTable) -> Self {
+        self.options.legend_table = table;
+        self
     }
-}
 
-impl PieChart<'_> {
-    /// Maximum ratio for vertical legend width (1/3 of available width).
-    const LEGEND_VERTICAL_MAX_RATIO: u16 = 3;
-
-    /// Minimum width for vertical legend to ensure readability.
-    const LEGEND_VERTICAL_MIN_WIDTH: u16 = 20;
+    /// Sets the number of columns of the grid legend.
+    ///
+    /// A [`LegendLayout::Vertical`] legend at the top or bottom of the chart
+    /// is drawn as a grid. By default (`0`) it has as many columns as fit in
+    /// the available width. When there are more rows than fit, the last
+    /// visible cell shows how many entries are left out as "+N more".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::{LegendPosition, PieChart, PieSlice};
+    ///
+    /// let piechart = PieChart::new(vec![PieSlice::auto("Rust", 45.0)])
+    ///     .legend_position(LegendPosition::Bottom)
+    ///     .legend_columns(3);
+    /// ```
+    #[must_use]
+    pub const fn legend_columns(mut self, columns: u16) -> Self {
+        self.options.legend_columns = columns;
+        self
+    }
 
-    /// Maximum ratio for horizontal legend width (2/5 = 40% of available width).
-    /// This keeps the pie chart proportional and prevents legend from dominating.
-    const LEGEND_HORIZONTAL_MAX_RATIO: u16 = 5;
+    /// Sets whether the grid legend is filled row by row or column by column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::{LegendFlow, LegendPosition, PieChart, PieSlice};
+    ///
+    /// let piechart = PieChart::new(vec![PieSlice::auto("Rust", 45.0)])
+    ///     .legend_position(LegendPosition::Top)
+    ///     .legend_flow(LegendFlow::ColumnMajor);
+    /// ```
+    #[must_use]
+    pub const fn legend_flow(mut self, flow: LegendFlow) -> Self {
+        self.options.legend_flow = flow;
+        self
+    }
 
-    /// Absolute maximum width for horizontal legends to prevent excessive space usage.
-    const LEGEND_HORIZONTAL_MAX_WIDTH: u16 = 60;
+    /// Sets the style patched onto the legend entry of the selected slice.
+    ///
+    /// Slices are selected through a [`PieChartState`] when the chart is
+    /// rendered as a [`StatefulWidget`]. Reversed by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Modifier, Style};
+    /// use tui_piechart::{PieChart, PieSlice};
+    ///
+    /// let piechart = PieChart::new(vec![PieSlice::auto("Rust", 45.0)])
+    ///     .highlight_style(Style::new().add_modifier(Modifier::BOLD | Modifier::UNDERLINED));
+    /// ```
+    #[must_use]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.options.highlight_style = style.into();
+        self
+    }
 
-    /// Absolute maximum height for vertical legends to prevent pie chart from being too small.
-    /// This allows 4 items with spacing (4 items * 2 lines = 8 lines, +1 for padding = 9).
-    const LEGEND_VERTICAL_MAX_HEIGHT: u16 = 9;
+    /// Sets how much space the legend takes up and how tightly its entries
+    /// are packed.
+    ///
+    /// See [`LegendConfig`] for the defaults.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::{LegendConfig, PieChart, PieSlice};
+    ///
+    /// let piechart = PieChart::new(vec![PieSlice::auto("Rust", 45.0)])
+    ///     .legend_config(LegendConfig::new().item_spacing(0).vertical_max_ratio(2));
+    /// ```
+    #[must_use]
+    pub const fn legend_config(mut self, config: LegendConfig) -> Self {
+        self.options.legend_config = config;
+        self
+    }
 
-    /// Height required for horizontal legend layout (single row with padding).
-    const LEGEND_HORIZONTAL_HEIGHT: u16 = 3;
+    /// Sets a header drawn with the legend, such as "Languages" or a total.
+    ///
+    /// The title is drawn above the entries, or below them when positioned
+    /// at the bottom, and aligned as the [`Title`] says. The legend is given
+    /// an extra line for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::title::{Title, TitleStyle};
+    /// use tui_piechart::{PieChart, PieSlice};
+    ///
+    /// let piechart = PieChart::new(vec![PieSlice::auto("Rust", 45.0)])
+    ///     .legend_title(Title::new(TitleStyle::Bold.apply("Languages")).left())
+    ///     .legend_separator(true);
+    /// ```
+    #[must_use]
+    pub fn legend_title<T: Into<Title>>(mut self, title: T) -> Self {
+        self.options.legend_title = Some(MaybeBorrowed::Owned(title.into()));
+        self
+    }
 
-    /// Space between pie chart and legend areas.
-    const LEGEND_SPACING: u16 = 1;
+    /// Sets the style of the legend title.
+    ///
+    /// Bold by default.
+    #[must_use]
+    pub fn legend_title_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.options.legend_title_style = style.into();
+        self
+    }
 
-    /// Inner padding for legend area.
-    const LEGEND_PADDING: u16 = 1;
+    /// Sets whether a horizontal rule separates the legend title from the
+    /// entries.
+    ///
+    /// Only drawn when the legend has a title.
+    #[must_use]
+    pub const fn legend_separator(mut self, show: bool) -> Self {
+        self.options.legend_separator = show;
+        self
+    }
 
-    fn render_piechart(&self, area: Rect, buf: &mut Buffer) {
-        if area.is_empty() || self.slices.is_empty() {
-            return;
-        }
+    /// Draws a border around the legend area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::border_style::BorderStyle;
+    /// use tui_piechart::{PieChart, PieSlice};
+    ///
+    /// let piechart = PieChart::new(vec![PieSlice::auto("Rust", 45.0)])
+    ///     .legend_title("Languages")
+    ///     .legend_border(BorderStyle::Rounded);
+    /// ```
+    #[must_use]
+    pub const fn legend_border(mut self, border: BorderStyle) -> Self {
+        self.options.legend_border = Some(border);
+        self
+    }
 
-        let total = self.total_value();
-        if total <= 0.0 {
-            return;
+    /// Draws a bar `width` cells wide in every legend entry, filled in
+    /// proportion to the slice's percentage with eighth blocks (`▏▎▍▌▋▊▉█`).
+    ///
+    /// The bar sits between the marker and the label, in the slice's color,
+    /// so the bars of a vertical legend line up. It makes the legend readable
+    /// where the pie itself is tiny. The legend is widened to fit the bars.
+    /// Table legends show values instead and draw no bars. `0`, the default,
+    /// turns bars off.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::{PieChart, PieSlice};
+    ///
+    /// // Renders entries like "■ ████▌      Rust 45.0%"
+    /// let piechart = PieChart::new(vec![PieSlice::auto("Rust", 45.0), PieSlice::auto("Go", 55.0)])
+    ///     .legend_bars(10);
+    /// ```
+    #[must_use]
+    pub const fn legend_bars(mut self, width: u16) -> Self {
+        self.options.legend_bar_width = width;
+        self
+    }
+
+    /// Sets how legend labels are shortened when an entry does not fit.
+    ///
+    /// Narrow legends shorten labels with "…" instead of clipping entries at
+    /// the edge, so the percentage stays visible. The default cuts the end of
+    /// the label. In a table legend, the label column narrows instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::{LegendTruncation, PieChart, PieSlice};
+    ///
+    /// let piechart = PieChart::new(vec![PieSlice::auto("TypeScript", 45.0)])
+    ///     .legend_truncation(LegendTruncation::Hide);
+    /// ```
+    #[must_use]
+    pub const fn legend_truncation(mut self, truncation: LegendTruncation) -> Self {
+        self.options.legend_truncation = truncation;
+        self
+    }
+
+    /// Fills the legend area with a style before drawing the entries.
+    ///
+    /// Meant for the overlay positions such as [`LegendPosition::TopRight`],
+    /// where the legend is drawn over the pie: the fill clears the pie behind
+    /// the legend and its padding. Without a background, an overlay legend
+    /// is drawn straight over the pie.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use tui_piechart::{LegendPosition, PieChart, PieSlice};
+    ///
+    /// let piechart = PieChart::new(vec![PieSlice::auto("Rust", 45.0)])
+    ///     .legend_position(LegendPosition::TopRight)
+    ///     .legend_background(Style::new().bg(Color::Black));
+    /// ```
+    #[must_use]
+    pub fn legend_background<S: Into<Style>>(mut self, style: S) -> Self {
+        self.options.legend_background = Some(style.into());
+        self
+    }
+
+    /// Applies a theme to the chart.
+    ///
+    /// Sets the base style, pie character, legend marker, palette, fill
+    /// patterns, and legend position, layout and alignment from the theme.
+    ///
+    /// The theme's border style is only used when the chart has no block
+    /// yet, in which case an untitled block with that border is installed. A
+    /// block set before `theme` is kept as it is. The theme's title style is
+    /// not applied to the chart or legend titles; build the block with
+    /// [`PieChartTheme::block`] to get a themed title.
+    ///
+    /// Builder calls made after `theme` override the theme's values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::{PieChart, PieChartTheme, PieSlice};
+    ///
+    /// let theme = PieChartTheme::high_contrast();
+    /// let piechart = PieChart::new(vec![PieSlice::auto("Rust", 45.0)])
+    ///     .block(theme.block("Languages"))
+    ///     .theme(&theme);
+    /// ```
+    #[must_use]
+    pub fn theme(mut self, theme: &PieChartTheme) -> Self {
+        self.options.style = theme.style;
+        self.options.pie_char = theme.pie_char;
+        self.options.legend_marker = theme.legend_marker;
+        self.options.palette = theme.palette;
+        self.options.auto_patterns = theme.auto_patterns;
+        self.options.legend_position = theme.legend_position;
+        self.options.legend_layout = theme.legend_layout;
+        self.options.legend_alignment = theme.legend_alignment;
+        if self.options.block.is_none() && theme.border_style.is_some() {
+            self.options.block = Some(MaybeBorrowed::Owned(theme.untitled_block()));
         }
+        self
+    }
 
-        match self.resolution {
-            Resolution::Standard => {
-                // Continue with standard rendering below
-            }
-            Resolution::Braille => {
-                self.render_piechart_braille(area, buf);
-                return;
-            }
+    /// Returns the pattern a slice is drawn with: its own pattern if it has
+    /// one, otherwise an automatic pattern if enabled, otherwise solid.
+    fn slice_pattern(&self, index: usize) -> FillPattern {
+        match self.slices.pattern(index) {
+            Some(pattern) => pattern,
+            None if self.options.auto_patterns => FillPattern::nth(index),
+            None => FillPattern::Solid,
         }
+    }
 
-        // Calculate layout with legend positioning
-        let (pie_area, legend_area_opt) = self.calculate_layout(area);
+    /// Returns the character used to fill a slice in standard resolution:
+    /// the slice's own character, then its pattern glyph, then the chart's.
+    fn slice_char(&self, index: usize) -> char {
+        self.slices
+            .pie_char(index)
+            .or_else(|| self.slice_pattern(index).glyph())
+            .unwrap_or(self.options.pie_char)
+    }
 
-        // Calculate the center and radius of the pie chart
-        // Account for terminal character aspect ratio (typically 1:2, chars are twice as tall as wide)
-        let center_x = pie_area.width / 2;
-        let center_y = pie_area.height / 2;
+    /// Returns the legend marker of a slice: the slice's own marker, then its
+    /// pattern glyph (so entries match their slices without color), then the
+    /// chart's marker.
+    fn slice_marker(&self, index: usize) -> Cow<'_, str> {
+        if let Some(marker) = self.slices.legend_marker(index) {
+            return Cow::Borrowed(marker);
+        }
+        match self.slice_pattern(index).glyph() {
+            Some(glyph) => Cow::Owned(glyph.to_string()),
+            None => Cow::Borrowed(self.options.legend_marker),
+        }
+    }
+
+    /// Returns the color a slice is drawn with: its own color if it has one,
+    /// otherwise the palette color for its index.
+    fn slice_color(&self, index: usize) -> Color {
+        self.slices
+            .style(index)
+            .fg
+            .unwrap_or_else(|| self.options.palette.color(index))
+    }
 
-        // Adjust radius for aspect ratio - use width as limiting factor
-        let radius = center_x.min(center_y * 2).saturating_sub(1);
+    /// Returns the style for a slice's pie cells: its color with the slice
+    /// style patched on top.
+    fn slice_style(&self, index: usize) -> Style {
+        Style::default()
+            .fg(self.slice_color(index))
+            .patch(self.slices.style(index))
+    }
 
-        // Draw the pie chart
-        let mut cumulative_percent = 0.0;
-        for slice in &self.slices {
-            let percent = self.percentage(slice);
-            self.render_slice(
-                pie_area,
-                buf,
-                center_x,
-                center_y,
-                radius,
-                cumulative_percent,
-                percent,
-                slice.color,
-            );
-            cumulative_percent += percent;
+    /// Returns the style for a slice's legend entry: the slice style with the
+    /// optional legend style patched on top.
+    fn slice_legend_style(&self, index: usize) -> Style {
+        let style = self.slice_style(index);
+        match self.slices.legend_style(index) {
+            Some(legend_style) => style.patch(legend_style),
+            None => style,
         }
+    }
 
-        // Draw legend if enabled
-        if let Some(legend_area) = legend_area_opt {
-            self.render_legend(buf, legend_area);
+    /// Returns the value a slice contributes under the chart's
+    /// [`ValuePolicy`], or `None` if the slice is left out.
+    fn effective_value(&self, index: usize) -> Option<f64> {
+        let value = self.slices.value(index);
+        match (ValueErrorKind::of(value), self.options.value_policy) {
+            (None, _) => Some(value),
+            (Some(_), ValuePolicy::Clamp) => Some(0.0),
+            (Some(_), ValuePolicy::Skip | ValuePolicy::Error) => None,
         }
     }
 
-    /// Returns the `(start_angle, end_angle, is_full_circle)` for a slice.
+    /// Returns the slices to draw in pie and legend order, with their total.
+    /// Under [`ValuePolicy::Error`] a chart with an invalid slice has none.
     ///
-    /// Angles start at the top of the circle (12 o'clock) and increase
-    /// clockwise. A slice covering the whole circle is flagged so callers can
-    /// fill the entire disc instead of collapsing to a single boundary line.
-    fn slice_angles(start_percent: f64, percent: f64) -> (f64, f64, bool) {
-        let start_angle = (start_percent / 100.0) * 2.0 * PI - PI / 2.0;
-        let end_angle = ((start_percent + percent) / 100.0) * 2.0 * PI - PI / 2.0;
-        let is_full_circle = percent >= 100.0 - f64::EPSILON;
-        (start_angle, end_angle, is_full_circle)
+    /// Rendering computes this once and passes it to the layout and drawing
+    /// code.
+    fn entries(&self) -> Entries {
+        let rejected = self.options.value_policy == ValuePolicy::Error && self.validate().is_err();
+        let entries: Vec<_> = (0..self.slices.len())
+            .filter(|_| !rejected)
+            .filter_map(|index| {
+                self.effective_value(index)
+                    .map(|value| Entry { index, value })
+            })
+            .collect();
+        let total = entries.iter().map(|entry| entry.value).sum();
+        let mut pie = entries.clone();
+        pie.sort_by(|a, b| self.compare_entries(a, b));
+        let legend = if self.options.legend_order == LegendOrder::Insertion {
+            entries
+        } else {
+            pie.clone()
+        };
+        Entries { pie, legend, total }
+    }
+
+    fn compare_entries(&self, a: &Entry, b: &Entry) -> Ordering {
+        match self.options.slice_order {
+            SliceOrder::Insertion => Ordering::Equal,
+            SliceOrder::ValueDescending => b.value.total_cmp(&a.value),
+            SliceOrder::ValueAscending => a.value.total_cmp(&b.value),
+            SliceOrder::Label => self.slices.label(a.index).cmp(self.slices.label(b.index)),
+        }
+    }
+
+    /// Calculates the percentage of `total` for the slice at `index`.
+    fn percentage(&self, index: usize, total: f64) -> f64 {
+        let value = self.effective_value(index).unwrap_or(0.0);
+        PieChart::value_percent(value, total)
+    }
+}
+
+/// A slice to draw.
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    /// Index of the slice in the data source, which also selects its palette
+    /// color and automatic pattern
+    index: usize,
+    /// Effective value under the chart's value policy
+    value: f64,
+}
+
+/// The slices of a chart, computed once per render.
+#[derive(Debug, Clone)]
+struct Entries {
+    /// Slices in the order they are drawn in the pie
+    pie: Vec<Entry>,
+    /// Slices in legend order
+    legend: Vec<Entry>,
+    /// Sum of the effective values
+    total: f64,
+}
+
+impl<D> Styled for PieChart<'_, D> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.options.style
+    }
+
+    fn set_style<S: Into<Style>>(mut self, style: S) -> Self::Item {
+        self.options.style = style.into();
+        self
+    }
+}
+
+impl<D: PieDataSource> Widget for PieChart<'_, D> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl<D: PieDataSource> Widget for &PieChart<'_, D> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        StatefulWidget::render(self, area, buf, &mut PieChartState::default());
+    }
+}
+
+impl<D: PieDataSource> StatefulWidget for PieChart<'_, D> {
+    type State = PieChartState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl<D: PieDataSource> StatefulWidget for &PieChart<'_, D> {
+    type State = PieChartState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.options.style);
+        let inner = if let Some(block) = self.options.block.as_deref() {
+            let inner_area = block.inner(area);
+            block.render(area, buf);
+            inner_area
+        } else {
+            area
+        };
+        let entries = self.entries();
+        let (chart, layout) = self.resolve_layout(inner, &entries);
+        chart.render_piechart(inner, layout, &entries, buf, state);
+    }
+}
+
+/// Layout constants and geometry that do not depend on the data source.
+///
+/// Generic code calls these as `PieChart::name`, which resolves to this
+/// implementation for every data source.
+impl PieChart<'_> {
+    /// Maximum ratio for horizontal legend width (2/5 = 40% of available width).
+    /// This keeps the pie chart proportional and prevents legend from dominating.
+    const LEGEND_HORIZONTAL_MAX_RATIO: u16 = 5;
+
+    /// Maximum number of rows of a wrapped horizontal legend; entries beyond
+    /// it are summarized as "+N more".
+    const LEGEND_HORIZONTAL_MAX_ROWS: u16 = 3;
+
+    /// Smallest pie area, in cells, an automatic legend position leaves.
+    const AUTO_MIN_PIE_SIZE: (u16, u16) = (10, 5);
+
+    /// Returns the number of cells `text` takes up in a terminal. Wide
+    /// characters such as CJK and most emoji take two cells, combining marks
+    /// none.
+    fn text_width(text: &str) -> u16 {
+        u16::try_from(text.width()).unwrap_or(u16::MAX)
+    }
+
+    /// Braille dot offsets within a cell and their bits (dots are numbered 1-8).
+    ///
+    /// Dot positions in a 2x4 grid:
+    /// 1 4
+    /// 2 5
+    /// 3 6
+    /// 7 8
+    const BRAILLE_DOTS: [(u16, u16, u32); 8] = [
+        (0, 0, 0x01), // dot 1
+        (0, 1, 0x02), // dot 2
+        (0, 2, 0x04), // dot 3
+        (1, 0, 0x08), // dot 4
+        (1, 1, 0x10), // dot 5
+        (1, 2, 0x20), // dot 6
+        (0, 3, 0x40), // dot 7
+        (1, 3, 0x80), // dot 8
+    ];
+
+    /// Draws the outline of a pie in standard resolution. `geometry` is the
+    /// center, relative to `area`, and radius of the pie itself.
+    fn render_ring(
+        area: Rect,
+        buf: &mut Buffer,
+        geometry: (u16, u16, u16),
+        symbol: char,
+        style: Style,
+    ) {
+        let (center_x, center_y, radius) = geometry;
+        let radius = f64::from(radius);
+        if radius <= 0.0 {
+            return;
+        }
+
+        for y in 0..area.height {
+            for x in 0..area.width {
+                // Rows are two units apart, so the band is thicker than one
+                // unit to keep the top and bottom of the ring closed.
+                let dx = f64::from(x) - f64::from(center_x);
+                let dy = (f64::from(y) - f64::from(center_y)) * 2.0;
+                let distance = (dx * dx + dy * dy).sqrt();
+                if distance <= radius && distance > radius - 1.5 {
+                    buf[(area.x + x, area.y + y)]
+                        .set_char(symbol)
+                        .set_style(style);
+                }
+            }
+        }
+    }
+
+    /// Draws the outline of a pie in braille resolution, with the same
+    /// `geometry` as [`render_ring`](Self::render_ring).
+    fn render_ring_braille(area: Rect, buf: &mut Buffer, geometry: (u16, u16, u16), style: Style) {
+        let (center_x, center_y, radius) = geometry;
+        let center_x = f64::from(center_x) * 2.0;
+        let center_y = f64::from(center_y) * 4.0;
+        let radius = f64::from(radius) * 2.0;
+        if radius <= 0.0 {
+            return;
+        }
+
+        for char_y in 0..area.height {
+            for char_x in 0..area.width {
+                let mut pattern = 0u32;
+                for (dx, dy, bit) in Self::BRAILLE_DOTS {
+                    let rel_x = f64::from(char_x) * 2.0 + f64::from(dx) - center_x;
+                    let rel_y = f64::from(char_y) * 4.0 + f64::from(dy) - center_y;
+                    let distance = (rel_x * rel_x + rel_y * rel_y).sqrt();
+                    if distance <= radius && distance > radius - 1.0 {
+                        pattern |= bit;
+                    }
+                }
+                if pattern > 0 {
+                    let braille_char = char::from_u32(0x2800 + pattern).unwrap_or('⠀');
+                    buf[(area.x + char_x, area.y + char_y)]
+                        .set_char(braille_char)
+                        .set_style(style);
+                }
+            }
+        }
+    }
+
+    /// Returns the width of each table legend column: the widest cell in it.
+    fn table_column_widths(rows: &[(Option<Entry>, [Cow<'_, str>; 4])]) -> [usize; 4] {
+        let mut widths = [0; 4];
+        for (_, cells) in rows {
+            for (width, cell) in widths.iter_mut().zip(cells) {
+                *width = (*width).max(cell.width());
+            }
+        }
+        widths
     }
 
     #[allow(clippy::too_many_arguments, clippy::similar_names)]
     fn render_slice(
-        &self,
         area: Rect,
         buf: &mut Buffer,
         center_x: u16,
@@ -704,7 +1965,8 @@ impl PieChart<'_> {
         radius: u16,
         start_percent: f64,
         percent: f64,
-        color: Color,
+        symbol: char,
+        style: Style,
     ) {
         if radius == 0 || percent <= 0.0 {
             return;
@@ -715,24 +1977,21 @@ impl PieChart<'_> {
         // to the same value and only the boundary line gets drawn.
         let (start_angle, end_angle, is_full_circle) = Self::slice_angles(start_percent, percent);
 
-        // Scan the entire area around the center
-        let scan_width = i32::from(radius + 1);
-        let scan_height = i32::from((radius / 2) + 1); // Account for aspect ratio
-
-        for dy in -scan_height..=scan_height {
-            for dx in -scan_width..=scan_width {
+        // Scan the box around the center, clipped to the area so that a pie
+        // much larger than the area costs no more than one that fills it
+        let (center_x, center_y) = (i32::from(center_x), i32::from(center_y));
+        let scan_width = i32::from(radius) + 1;
+        let scan_height = i32::from(radius) / 2 + 1; // Account for aspect ratio
+        let scan_x =
+            (-scan_width).max(-center_x)..=scan_width.min(i32::from(area.width) - 1 - center_x);
+        let scan_y =
+            (-scan_height).max(-center_y)..=scan_height.min(i32::from(area.height) - 1 - center_y);
+
+        for dy in scan_y {
+            for dx in scan_x.clone() {
                 // Calculate actual position in buffer
-                let x = i32::from(area.x) + i32::from(center_x) + dx;
-                let y = i32::from(area.y) + i32::from(center_y) + dy;
-
-                // Check bounds
-                if x < i32::from(area.x)
-                    || x >= i32::from(area.x + area.width)
-                    || y < i32::from(area.y)
-                    || y >= i32::from(area.y + area.height)
-                {
-                    continue;
-                }
+                let x = i32::from(area.x) + center_x + dx;
+                let y = i32::from(area.y) + center_y + dy;
 
                 // Adjust for aspect ratio: multiply y distance by 2
                 #[allow(clippy::cast_precision_loss)]
@@ -754,7 +2013,7 @@ impl PieChart<'_> {
                         #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
                         {
                             let cell = &mut buf[(x as u16, y as u16)];
-                            cell.set_char(self.pie_char).set_fg(color);
+                            cell.set_char(symbol).set_style(style);
                         }
                     }
                 }
@@ -762,734 +2021,3479 @@ impl PieChart<'_> {
         }
     }
 
-    fn is_angle_in_slice(angle: f64, start: f64, end: f64) -> bool {
-        // Normalize angles to [0, 2π]
-        let normalize = |a: f64| {
-            let mut normalized = a % (2.0 * PI);
-            if normalized < 0.0 {
-                normalized += 2.0 * PI;
-            }
-            normalized
-        };
-
-        let norm_angle = normalize(angle);
-        let norm_start = normalize(start);
-        let norm_end = normalize(end);
-
-        if norm_start <= norm_end {
-            norm_angle >= norm_start && norm_angle <= norm_end
-        } else {
-            // Handle wrap around at 2π/0
-            norm_angle >= norm_start || norm_angle <= norm_end
+    fn layout_horizontal_split(
+        area: Rect,
+        legend_width: u16,
+        legend_on_left: bool,
+        config: &LegendConfig,
+    ) -> (Rect, Option<Rect>) {
+        if area.width <= legend_width {
+            return (area, None);
         }
-    }
 
-    fn format_legend_text(&self, slice: &PieSlice, total: f64, spacing: &str) -> String {
-        if self.show_percentages {
-            let percent = Self::value_percent(slice.value, total);
-            format!(
-                "{} {} {:.1}%{}",
-                self.legend_marker, slice.label, percent, spacing
+        let pie_width = area
+            .width
+            .saturating_sub(legend_width.saturating_add(config.spacing));
+        let legend_y = area.y.saturating_add(config.padding);
+        let legend_height = area.height.saturating_sub(config.padding.saturating_mul(2));
+
+        if legend_on_left {
+            (
+                Rect {
+                    x: area.right() - pie_width,
+                    y: area.y,
+                    width: pie_width,
+                    height: area.height,
+                },
+                Some(Rect {
+                    x: area.x,
+                    y: legend_y,
+                    width: legend_width,
+                    height: legend_height,
+                }),
             )
         } else {
-            format!("{} {}{}", self.legend_marker, slice.label, spacing)
+            (
+                Rect {
+                    x: area.x,
+                    y: area.y,
+                    width: pie_width,
+                    height: area.height,
+                },
+                Some(Rect {
+                    x: area.right() - legend_width,
+                    y: legend_y,
+                    width: legend_width,
+                    height: legend_height,
+                }),
+            )
         }
     }
 
-    /// Rendered display width of a single legend entry (marker, label, and an
-    /// optional percentage) followed by two spaces of trailing padding.
-    fn legend_item_width(&self, slice: &PieSlice, total: f64) -> u16 {
-        u16::try_from(self.format_legend_text(slice, total, "  ").chars().count())
-            .unwrap_or(u16::MAX)
-    }
-
-    fn calculate_aligned_x(&self, legend_area: Rect, content_width: u16) -> u16 {
-        match self.legend_alignment {
-            LegendAlignment::Left => legend_area.x,
-            LegendAlignment::Center => {
-                legend_area.x + (legend_area.width.saturating_sub(content_width)) / 2
-            }
-            LegendAlignment::Right => {
-                legend_area.x + legend_area.width.saturating_sub(content_width)
-            }
+    fn layout_vertical_split(
+        area: Rect,
+        legend_height: u16,
+        legend_on_top: bool,
+        config: &LegendConfig,
+    ) -> (Rect, Option<Rect>) {
+        if area.height <= legend_height {
+            return (area, None);
         }
-    }
 
-    fn render_legend(&self, buf: &mut Buffer, legend_area: Rect) {
-        let total = self.total_value();
+        let pie_height = area
+            .height
+            .saturating_sub(legend_height.saturating_add(config.spacing));
+        let legend_x = area.x.saturating_add(config.padding);
+        let legend_width = area.width.saturating_sub(config.padding.saturating_mul(2));
 
-        match self.legend_layout {
-            LegendLayout::Vertical => {
-                self.render_vertical_legend(buf, legend_area, total);
-            }
-            LegendLayout::Horizontal => {
-                self.render_horizontal_legend(buf, legend_area, total);
-            }
-        }
-    }
+        if legend_on_top {
+            (
+                Rect {
+                    x: area.x,
+                    y: area.bottom() - pie_height,
+                    width: area.width,
+                    height: pie_height,
+                },
+                Some(Rect {
+                    x: legend_x,
+                    y: area.y.saturating_add(config.padding),
+                    width: legend_width,
+                    height: legend_height.saturating_sub(config.padding),
+                }),
+            )
+        } else {
+            (
+                Rect {
+                    x: area.x,
+                    y: area.y,
+                    width: area.width,
+                    height: pie_height,
+                },
+                Some(Rect {
+                    x: legend_x,
+                    y: area.bottom() - legend_height,
+                    width: legend_width,
+                    height: legend_height.saturating_sub(config.padding),
+                }),
+            )
+        }
+    }
 
-    fn render_vertical_legend(&self, buf: &mut Buffer, legend_area: Rect, total: f64) {
-        for (idx, slice) in self.slices.iter().enumerate() {
-            #[allow(clippy::cast_possible_truncation)]
-            let y_offset = (idx as u16) * 2;
+    /// Draws a bar `width` cells wide, filled to `percent` in eighths of a
+    /// cell and padded with spaces.
+    fn percent_bar(percent: f64, width: u16) -> String {
+        const PARTIAL: [&str; 8] = [
+            "",
+            block::ONE_EIGHTH,
+            block::ONE_QUARTER,
+            block::THREE_EIGHTHS,
+            block::HALF,
+            block::FIVE_EIGHTHS,
+            block::THREE_QUARTERS,
+            block::SEVEN_EIGHTHS,
+        ];
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let eighths = (percent.clamp(0.0, 100.0) / 100.0 * f64::from(width) * 8.0).round() as usize;
+        let width = usize::from(width);
+        let (full, partial) = (eighths / 8, eighths % 8);
+        let mut bar = block::FULL.repeat(full);
+        bar.push_str(PARTIAL[partial]);
+        let filled = full + usize::from(partial > 0);
+        bar.push_str(&" ".repeat(width - filled));
+        bar
+    }
 
-            if y_offset >= legend_area.height {
-                break;
-            }
+    /// Computes the percentage a value represents of a total.
+    ///
+    /// Returns `0.0` when `total` is not strictly positive, which keeps the
+    /// arithmetic safe for empty charts and all-zero data sets.
+    fn value_percent(value: f64, total: f64) -> f64 {
+        if total > 0.0 {
+            (value / total) * 100.0
+        } else {
+            0.0
+        }
+    }
 
-            let legend_text = self.format_legend_text(slice, total, "");
-            #[allow(clippy::cast_possible_truncation)]
-            let text_width = u16::try_from(legend_text.chars().count()).unwrap_or(u16::MAX);
-            let x_pos = self.calculate_aligned_x(legend_area, text_width);
+    /// Returns the `(start_angle, end_angle, is_full_circle)` for a slice.
+    ///
+    /// Angles start at the top of the circle (12 o'clock) and increase
+    /// clockwise. A slice covering the whole circle is flagged so callers can
+    /// fill the entire disc instead of collapsing to a single boundary line.
+    fn slice_angles(start_percent: f64, percent: f64) -> (f64, f64, bool) {
+        let start_angle = (start_percent / 100.0) * 2.0 * PI - PI / 2.0;
+        let end_angle = ((start_percent + percent) / 100.0) * 2.0 * PI - PI / 2.0;
+        let is_full_circle = percent >= 100.0 - f64::EPSILON;
+        (start_angle, end_angle, is_full_circle)
+    }
 
-            let line = Line::from(vec![Span::styled(
-                legend_text,
-                Style::default().fg(slice.color),
-            )]);
-            let item_area = Rect {
-                x: x_pos,
-                y: legend_area.y + y_offset,
-                width: text_width.min(legend_area.width),
-                height: 1,
-            };
+    fn is_angle_in_slice(angle: f64, start: f64, end: f64) -> bool {
+        // Normalize angles to [0, 2π]
+        let normalize = |a: f64| {
+            let mut normalized = a % (2.0 * PI);
+            if normalized < 0.0 {
+                normalized += 2.0 * PI;
+            }
+            normalized
+        };
 
-            line.render(item_area, buf);
+        let norm_angle = normalize(angle);
+        let norm_start = normalize(start);
+        let norm_end = normalize(end);
+
+        if norm_start <= norm_end {
+            norm_angle >= norm_start && norm_angle <= norm_end
+        } else {
+            // Handle wrap around at 2π/0
+            norm_angle >= norm_start || norm_angle <= norm_end
         }
     }
+}
 
-    fn render_horizontal_legend(&self, buf: &mut Buffer, legend_area: Rect, total: f64) {
-        let mut total_width = 0u16;
-        let mut item_widths = Vec::new();
-
-        for slice in &self.slices {
-            let legend_text = self.format_legend_text(slice, total, "  ");
-            #[allow(clippy::cast_possible_truncation)]
-            let text_width = u16::try_from(legend_text.chars().count()).unwrap_or(u16::MAX);
-            item_widths.push(text_width);
-            total_width = total_width.saturating_add(text_width);
+impl<D: PieDataSource> PieChart<'_, D> {
+    /// Draws the chart in `area`, split between the pie and the legend as
+    /// `layout` says.
+    fn render_piechart(
+        &self,
+        area: Rect,
+        layout: (Rect, Option<Rect>),
+        entries: &Entries,
+        buf: &mut Buffer,
+        state: &mut PieChartState,
+    ) {
+        if area.is_empty() {
+            return;
         }
 
-        let start_x = self.calculate_aligned_x(legend_area, total_width.min(legend_area.width));
-        let mut x_offset = 0u16;
+        // No slices, or nothing but zero and skipped values
+        let total = entries.total;
+        if total <= 0.0 {
+            if let Some(placeholder) = &self.options.placeholder {
+                self.render_placeholder(placeholder, area, layout, entries, buf, state);
+            }
+            return;
+        }
 
-        for (idx, slice) in self.slices.iter().enumerate() {
-            if x_offset >= legend_area.width {
-                break;
+        match self.options.resolution {
+            Resolution::Standard => {
+                // Continue with standard rendering below
+            }
+            Resolution::Braille => {
+                self.render_piechart_braille(layout, entries, buf, state);
+                return;
             }
+        }
 
-            let legend_text = self.format_legend_text(slice, total, "  ");
-            let text_width = item_widths[idx];
+        let (pie_area, legend_area_opt) = layout;
 
-            let line = Line::from(vec![Span::styled(
-                legend_text,
-                Style::default().fg(slice.color),
-            )]);
-            let item_area = Rect {
-                x: start_x + x_offset,
-                y: legend_area.y,
-                width: text_width.min(legend_area.width.saturating_sub(x_offset)),
+        // Calculate the center and radius of the pie chart
+        let (center_x, center_y, radius) = self.pie_geometry(pie_area);
+
+        // Draw the pie chart
+        let mut cumulative_percent = 0.0;
+        for entry in &entries.pie {
+            let percent = self.percentage(entry.index, total);
+            PieChart::render_slice(
+                pie_area,
+                buf,
+                center_x,
+                center_y,
+                radius,
+                cumulative_percent,
+                percent,
+                self.slice_char(entry.index),
+                self.slice_style(entry.index),
+            );
+            cumulative_percent += percent;
+        }
+
+        // Draw legend if enabled
+        if let Some(legend_area) = legend_area_opt {
+            self.render_legend(buf, legend_area, entries, state);
+        }
+    }
+
+    fn render_placeholder(
+        &self,
+        placeholder: &Placeholder,
+        area: Rect,
+        layout: (Rect, Option<Rect>),
+        entries: &Entries,
+        buf: &mut Buffer,
+        state: &mut PieChartState,
+    ) {
+        let (pie_area, legend_area_opt) = if placeholder.show_legend && !entries.pie.is_empty() {
+            layout
+        } else {
+            (area, None)
+        };
+
+        let geometry = self.pie_geometry(pie_area);
+        if let Some(style) = placeholder.ring {
+            match self.options.resolution {
+                Resolution::Standard => {
+                    PieChart::render_ring(pie_area, buf, geometry, placeholder.ring_char, style);
+                }
+                Resolution::Braille => {
+                    PieChart::render_ring_braille(pie_area, buf, geometry, style);
+                }
+            }
+        }
+
+        if !placeholder.text.is_empty() && !pie_area.is_empty() {
+            let text_area = Rect {
+                y: pie_area.y + pie_area.height / 2,
                 height: 1,
+                ..pie_area
             };
+            Line::styled(placeholder.text, placeholder.text_style)
+                .centered()
+                .render(text_area, buf);
+        }
 
-            line.render(item_area, buf);
-            x_offset = x_offset.saturating_add(text_width);
+        if let Some(legend_area) = legend_area_opt {
+            self.render_legend(buf, legend_area, entries, state);
         }
     }
 
-    #[allow(clippy::too_many_lines)]
-    fn calculate_layout(&self, area: Rect) -> (Rect, Option<Rect>) {
-        if !self.show_legend || area.width < 20 || area.height < 10 {
-            return (area, None);
+    /// Returns the center of the pie, relative to `area`, and its radius, in
+    /// cells. The radius follows [`PieRadius`], and the pie is placed in the
+    /// area by the pie alignment as if the box around it were a widget.
+    fn pie_geometry(&self, area: Rect) -> (u16, u16, u16) {
+        // Rows are twice as tall as columns are wide
+        let fit = (area.width / 2).min(area.height / 2 * 2).saturating_sub(1);
+        let radius = match self.options.radius {
+            PieRadius::Fit => fit,
+            PieRadius::Fixed(radius) => radius,
+            PieRadius::Max(radius) => radius.min(fit),
+        };
+
+        // The box a pie of this radius fills when it fits the area exactly,
+        // so that centered pies sit where they always have. Its first column
+        // and row may be blank; left and top aligned pies start at the edge
+        // instead, leaving room for braille dots half a row further out.
+        let (width, height) = (i32::from(area.width), i32::from(area.height));
+        let half_width = i32::from(radius) + 1;
+        let half_height = (half_width + 1) / 2;
+        let center_x = match self.options.pie_alignment {
+            HorizontalAlignment::Left => i32::from(radius),
+            HorizontalAlignment::Center => (width - 2 * half_width).div_euclid(2) + half_width,
+            HorizontalAlignment::Right => width - half_width,
+        };
+        let center_y = match self.options.pie_vertical_alignment {
+            VerticalAlignment::Top => (i32::from(radius) + 1) / 2,
+            VerticalAlignment::Center => (height - 2 * half_height).div_euclid(2) + half_height,
+            VerticalAlignment::Bottom => height - half_height,
+        };
+        let clamp = |center: i32| u16::try_from(center.max(0)).unwrap_or(u16::MAX);
+        (clamp(center_x), clamp(center_y), radius)
+    }
+
+    fn format_legend_text(&self, index: usize, total: f64, spacing: &str) -> String {
+        self.format_legend_entry(index, total, self.slices.label(index), spacing)
+    }
+
+    /// Formats a legend entry in at most `max_width` cells if shortening its
+    /// label is enough, as the [`LegendTruncation`] says. The marker, bar and
+    /// percentage are kept whole.
+    fn fit_legend_text(&self, index: usize, total: f64, max_width: u16) -> String {
+        let text = self.format_legend_text(index, total, "");
+        let overflow = usize::from(PieChart::text_width(&text).saturating_sub(max_width));
+        if overflow == 0 {
+            return text;
         }
+        let label = self.slices.label(index);
+        let label = self
+            .options
+            .legend_truncation
+            .truncate(label, label.width().saturating_sub(overflow));
+        self.format_legend_entry(index, total, &label, "")
+    }
 
-        // Vertical layout uses Left/Right positions, Horizontal layout uses Top/Bottom
-        match (self.legend_position, self.legend_layout) {
-            // Left/Right with Vertical layout - proper vertical stacking on sides
-            (LegendPosition::Left | LegendPosition::Right, LegendLayout::Vertical) => {
-                let legend_width = self
-                    .calculate_legend_width()
-                    .min(area.width / Self::LEGEND_VERTICAL_MAX_RATIO)
-                    .max(Self::LEGEND_VERTICAL_MIN_WIDTH);
-                let is_left = matches!(self.legend_position, LegendPosition::Left);
-                Self::layout_horizontal_split(area, legend_width, is_left)
-            }
-            // Top/Bottom with Horizontal layout - single row at top/bottom
-            (LegendPosition::Top | LegendPosition::Bottom, LegendLayout::Horizontal) => {
-                let is_top = matches!(self.legend_position, LegendPosition::Top);
-                Self::layout_vertical_split(area, Self::LEGEND_HORIZONTAL_HEIGHT, is_top)
-            }
-            // Fallback: use horizontal layout for incompatible combinations
-            (LegendPosition::Left | LegendPosition::Right, LegendLayout::Horizontal) => {
-                // Horizontal layout on sides - allocate limited width
-                let legend_width = self
-                    .calculate_legend_horizontal_width()
-                    .min(
-                        (area.width * (Self::LEGEND_HORIZONTAL_MAX_RATIO - 1))
-                            / Self::LEGEND_HORIZONTAL_MAX_RATIO,
-                    )
-                    .min(Self::LEGEND_HORIZONTAL_MAX_WIDTH);
-                let is_left = matches!(self.legend_position, LegendPosition::Left);
-                Self::layout_horizontal_split(area, legend_width, is_left)
+    fn format_legend_entry(&self, index: usize, total: f64, label: &str, spacing: &str) -> String {
+        let marker = self.slice_marker(index);
+        let value = self.effective_value(index).unwrap_or(0.0);
+        let percent = PieChart::value_percent(value, total);
+        let bar = if self.options.legend_bar_width > 0 {
+            format!(
+                "{} ",
+                PieChart::percent_bar(percent, self.options.legend_bar_width)
+            )
+        } else {
+            String::new()
+        };
+        if !self.options.show_percentages {
+            format!("{marker} {bar}{label}{spacing}")
+        } else if label.is_empty() {
+            format!("{marker} {bar}{percent:.1}%{spacing}")
+        } else {
+            format!("{marker} {bar}{label} {percent:.1}%{spacing}")
+        }
+    }
+
+    /// Rendered display width of a single legend entry (marker, optional bar,
+    /// label, and optional percentage) followed by two spaces of trailing
+    /// padding.
+    fn legend_item_width(&self, index: usize, total: f64) -> u16 {
+        PieChart::text_width(&self.format_legend_text(index, total, "  "))
+    }
+
+    fn calculate_aligned_x(&self, legend_area: Rect, content_width: u16) -> u16 {
+        match self.options.legend_alignment {
+            LegendAlignment::Left => legend_area.x,
+            LegendAlignment::Center => {
+                legend_area.x + (legend_area.width.saturating_sub(content_width)) / 2
             }
-            (LegendPosition::Top | LegendPosition::Bottom, LegendLayout::Vertical) => {
-                // Vertical layout at top/bottom - use 2-column grid with minimal height
-                let legend_height = self.calculate_vertical_grid_height(area.width);
-                let is_top = matches!(self.legend_position, LegendPosition::Top);
-                Self::layout_vertical_split(area, legend_height, is_top)
+            LegendAlignment::Right => {
+                legend_area.x + legend_area.width.saturating_sub(content_width)
             }
         }
     }
 
-    fn calculate_vertical_grid_height(&self, available_width: u16) -> u16 {
-        // For vertical layout at top/bottom, use 2-column grid
-        let max_item_width = self.calculate_legend_width();
-        let columns = (available_width.saturating_sub(Self::LEGEND_PADDING * 2)
-            / max_item_width.max(1))
-        .clamp(1, 2);
+    /// Returns the style of a legend entry, highlighted if it is selected.
+    fn entry_legend_style(&self, entry: Entry, state: &PieChartState) -> Style {
+        let style = self.slice_legend_style(entry.index);
+        if state.selected == Some(entry.index) {
+            style.patch(self.options.highlight_style)
+        } else {
+            style
+        }
+    }
 
-        #[allow(clippy::cast_possible_truncation)]
-        let num_items = self.slices.len() as u16;
+    fn render_legend(
+        &self,
+        buf: &mut Buffer,
+        legend_area: Rect,
+        entries: &Entries,
+        state: &mut PieChartState,
+    ) {
+        if let Some(style) = self.options.legend_background {
+            Clear.render(legend_area, buf);
+            buf.set_style(legend_area, self.options.style.patch(style));
+        }
+        // Overlay areas include their padding, which the background covers
+        let legend_area = if self.options.legend_position.is_overlay() {
+            let padding = self.options.legend_config.padding;
+            legend_area.inner(Margin::new(padding, padding))
+        } else {
+            legend_area
+        };
+        let legend_area = self.render_legend_frame(buf, legend_area);
+        if legend_area.is_empty() {
+            return;
+        }
 
-        // Calculate rows: ceil(items / columns)
-        let rows = num_items.div_ceil(columns);
-        // Each row needs 2 lines (item + spacing), plus account for padding that will be subtracted
-        (rows * 2 + Self::LEGEND_PADDING).clamp(4, Self::LEGEND_VERTICAL_MAX_HEIGHT)
+        match self.options.legend_layout {
+            LegendLayout::Vertical
+                if matches!(
+                    self.options.legend_position,
+                    LegendPosition::Top | LegendPosition::Bottom
+                ) =>
+            {
+                self.render_grid_legend(buf, legend_area, entries, state);
+            }
+            LegendLayout::Vertical => {
+                self.render_vertical_legend(buf, legend_area, entries, state);
+            }
+            LegendLayout::Horizontal => {
+                self.render_horizontal_legend(buf, legend_area, entries, state);
+            }
+            LegendLayout::Table => {
+                self.render_table_legend(buf, legend_area, entries, state);
+            }
+        }
     }
 
-    fn layout_horizontal_split(
-        area: Rect,
-        legend_width: u16,
-        legend_on_left: bool,
-    ) -> (Rect, Option<Rect>) {
-        if area.width <= legend_width {
-            return (area, None);
+    /// Draws the legend border, title and separator, and returns the area
+    /// left for the entries.
+    fn render_legend_frame(&self, buf: &mut Buffer, legend_area: Rect) -> Rect {
+        let mut area = legend_area;
+        if let Some(border) = self.options.legend_border {
+            let block = border.block();
+            area = block.inner(legend_area);
+            block.render(legend_area, buf);
+        }
+        let Some(title) = &self.options.legend_title else {
+            return area;
+        };
+        if area.is_empty() {
+            return area;
         }
 
-        let pie_width = area
-            .width
-            .saturating_sub(legend_width + Self::LEGEND_SPACING);
+        let header = (1 + u16::from(self.options.legend_separator)).min(area.height);
+        let entries_height = area.height - header;
+        let (title_y, rule_y, entries_y) = if title.position() == TitlePosition::Bottom {
+            let title_y = area.bottom() - 1;
+            (title_y, title_y.saturating_sub(1), area.y)
+        } else {
+            (area.y, area.y + 1, area.y + header)
+        };
 
-        if legend_on_left {
-            (
+        title
+            .render()
+            .patch_style(self.options.legend_title_style)
+            .alignment(title.alignment().into())
+            .render(
                 Rect {
-                    x: area.x + legend_width + Self::LEGEND_SPACING,
-                    y: area.y,
-                    width: pie_width,
-                    height: area.height,
+                    y: title_y,
+                    height: 1,
+                    ..area
                 },
-                Some(Rect {
-                    x: area.x,
-                    y: area.y + Self::LEGEND_PADDING,
-                    width: legend_width,
-                    height: area.height.saturating_sub(Self::LEGEND_PADDING * 2),
-                }),
-            )
+                buf,
+            );
+        if header > 1 {
+            let rule = ratatui::symbols::line::HORIZONTAL.repeat(usize::from(area.width));
+            buf.set_string(area.x, rule_y, rule, Style::new());
+        }
+
+        Rect {
+            y: entries_y,
+            height: entries_height,
+            ..area
+        }
+    }
+
+    /// Returns the width and height that the legend border, title and
+    /// separator add to the entries, and the width of the title.
+    fn legend_frame_size(&self) -> (u16, u16, u16) {
+        let border = if self.options.legend_border.is_some() {
+            2
         } else {
-            (
-                Rect {
-                    x: area.x,
-                    y: area.y,
-                    width: pie_width,
-                    height: area.height,
-                },
-                Some(Rect {
-                    x: area.x + pie_width + Self::LEGEND_SPACING,
-                    y: area.y + Self::LEGEND_PADDING,
-                    width: legend_width,
-                    height: area.height.saturating_sub(Self::LEGEND_PADDING * 2),
-                }),
-            )
+            0
+        };
+        let (header, title_width) = self.options.legend_title.as_ref().map_or((0, 0), |title| {
+            let width = u16::try_from(title.render().width()).unwrap_or(u16::MAX);
+            (1 + u16::from(self.options.legend_separator), width)
+        });
+        (border, border + header, title_width)
+    }
+
+    /// Draws one entry per line, separated by the configured item spacing.
+    /// When the entries do not fit, the first and last lines show how many
+    /// are scrolled out of view above and below, and `state` decides which
+    /// entries are shown. A legend too short for both lines shows a single
+    /// entry, below one line counting the others if there is room.
+    fn render_vertical_legend(
+        &self,
+        buf: &mut Buffer,
+        legend_area: Rect,
+        entries: &Entries,
+        state: &mut PieChartState,
+    ) {
+        let total = entries.total;
+        let entries = &entries.legend;
+        let pitch = self.options.legend_config.item_pitch();
+        let capacity = usize::from(legend_area.height.div_ceil(pitch));
+        let compact = legend_area.height <= 2;
+        let (visible, first_y) = if entries.len() <= capacity {
+            state.legend_offset = 0;
+            (entries.len(), 0)
+        } else {
+            // Leave the first and last lines for the scroll indicators
+            let visible = if compact {
+                1
+            } else {
+                usize::from(legend_area.height.saturating_add(pitch).saturating_sub(3) / pitch)
+            };
+            let selected = state
+                .selected
+                .and_then(|index| entries.iter().position(|entry| entry.index == index));
+            state.scroll_to(entries.len(), visible, selected);
+            (visible, if compact { legend_area.height - 1 } else { 1 })
+        };
+        let offset = state.legend_offset;
+
+        for (row, entry) in entries.iter().skip(offset).take(visible).enumerate() {
+            #[allow(clippy::cast_possible_truncation)]
+            let y_offset = first_y.saturating_add((row as u16).saturating_mul(pitch));
+
+            let legend_text = self.fit_legend_text(entry.index, total, legend_area.width);
+            let text_width = PieChart::text_width(&legend_text);
+            let x_pos = self.calculate_aligned_x(legend_area, text_width);
+
+            let line = Line::from(vec![Span::styled(
+                legend_text,
+                self.entry_legend_style(*entry, state),
+            )]);
+            let item_area = Rect {
+                x: x_pos,
+                y: legend_area.y + y_offset,
+                width: text_width.min(legend_area.width),
+                height: 1,
+            };
+
+            line.render(item_area, buf);
+        }
+
+        let below = entries.len().saturating_sub(offset + visible);
+        if compact {
+            if first_y > 0 && offset + below > 0 {
+                let text = Self::more_indicator(offset + below);
+                self.render_scroll_indicator(buf, legend_area, 0, &text);
+            }
+            return;
+        }
+        if offset > 0 {
+            self.render_scroll_indicator(buf, legend_area, 0, &format!("▲ {offset} more"));
+        }
+        if below > 0 {
+            let y_offset = legend_area.height - 1;
+            self.render_scroll_indicator(buf, legend_area, y_offset, &format!("▼ {below} more"));
+        }
+    }
+
+    fn render_scroll_indicator(
+        &self,
+        buf: &mut Buffer,
+        legend_area: Rect,
+        y_offset: u16,
+        text: &str,
+    ) {
+        let text_width = PieChart::text_width(text);
+        let item_area = Rect {
+            x: self.calculate_aligned_x(legend_area, text_width),
+            y: legend_area.y + y_offset,
+            width: text_width.min(legend_area.width),
+            height: 1,
+        };
+        Line::styled(text, Style::new().add_modifier(Modifier::DIM)).render(item_area, buf);
+    }
+
+    /// Draws the entries in a grid. When rows are cut off by the height of
+    /// the legend, the last visible cell on screen, at the bottom right, says
+    /// how many entries are hidden.
+    fn render_grid_legend(
+        &self,
+        buf: &mut Buffer,
+        legend_area: Rect,
+        entries: &Entries,
+        state: &PieChartState,
+    ) {
+        let total = entries.total;
+        let columns = self.legend_grid_columns(legend_area.width, entries);
+        #[allow(clippy::cast_possible_truncation)]
+        let rows = (entries.legend.len() as u16).div_ceil(columns);
+
+        // Columns are as wide as the widest item; the last one needs no gap.
+        let column_width = self.calculate_legend_width(entries);
+        let item_width = column_width.saturating_sub(4);
+        let grid_width = column_width
+            .saturating_mul(columns - 1)
+            .saturating_add(item_width);
+        let start_x = self.calculate_aligned_x(legend_area, grid_width.min(legend_area.width));
+
+        let count = entries.legend.len();
+        let cells: Vec<_> = entries
+            .legend
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| {
+                #[allow(clippy::cast_possible_truncation)]
+                let i = i as u16;
+                let (row, column) = match self.options.legend_flow {
+                    LegendFlow::RowMajor => (i / columns, i % columns),
+                    LegendFlow::ColumnMajor => (i % rows, i / rows),
+                };
+                let x_offset =
+                    (start_x - legend_area.x).saturating_add(column.saturating_mul(column_width));
+                let y_offset = row.saturating_mul(self.options.legend_config.item_pitch());
+                (x_offset < legend_area.width && y_offset < legend_area.height)
+                    .then_some((*entry, x_offset, y_offset))
+            })
+            .collect();
+        let hidden = count - cells.len();
+        // In column-major order the last entry shown need not be the last cell
+        let last_cell = cells
+            .iter()
+            .enumerate()
+            .max_by_key(|&(_, &(_, x_offset, y_offset))| (y_offset, x_offset))
+            .map(|(i, _)| i);
+
+        for (i, &(entry, x_offset, y_offset)) in cells.iter().enumerate() {
+            let max_width = legend_area.width - x_offset;
+            let (legend_text, style) = if hidden > 0 && Some(i) == last_cell {
+                let text = Self::more_indicator(hidden + 1);
+                (text, Style::new().add_modifier(Modifier::DIM))
+            } else {
+                let text = self.fit_legend_text(entry.index, total, max_width);
+                (text, self.entry_legend_style(entry, state))
+            };
+            let text_width = PieChart::text_width(&legend_text);
+            let item_area = Rect {
+                x: legend_area.x + x_offset,
+                y: legend_area.y + y_offset,
+                width: text_width.min(max_width),
+                height: 1,
+            };
+            Line::styled(legend_text, style).render(item_area, buf);
+        }
+    }
+
+    /// Returns the number of columns of a grid legend `width` cells wide: the
+    /// configured count, or as many as fit, but never more than there are
+    /// entries.
+    fn legend_grid_columns(&self, width: u16, entries: &Entries) -> u16 {
+        let columns = if self.options.legend_columns > 0 {
+            self.options.legend_columns
+        } else {
+            width / self.calculate_legend_width(entries).max(1)
+        };
+        #[allow(clippy::cast_possible_truncation)]
+        let num_items = entries.legend.len() as u16;
+        columns.min(num_items).max(1)
+    }
+
+    fn render_horizontal_legend(
+        &self,
+        buf: &mut Buffer,
+        legend_area: Rect,
+        entries: &Entries,
+        state: &PieChartState,
+    ) {
+        let max_rows = legend_area.height.min(PieChart::LEGEND_HORIZONTAL_MAX_ROWS);
+        let (rows, hidden) = self.horizontal_legend_rows(legend_area.width, max_rows, entries);
+        let last_row = rows.len().saturating_sub(1);
+
+        for (row, items) in rows.into_iter().enumerate() {
+            let mut spans: Vec<_> = items
+                .into_iter()
+                .map(|(entry, text)| Span::styled(text, self.entry_legend_style(entry, state)))
+                .collect();
+            if row == last_row && hidden > 0 {
+                spans.push(Span::styled(
+                    Self::more_indicator(hidden),
+                    Style::new().add_modifier(Modifier::DIM),
+                ));
+            }
+
+            let line = Line::from(spans);
+            let row_width = u16::try_from(line.width()).unwrap_or(u16::MAX);
+            #[allow(clippy::cast_possible_truncation)]
+            let item_area = Rect {
+                x: self.calculate_aligned_x(legend_area, row_width.min(legend_area.width)),
+                y: legend_area.y + row as u16,
+                width: row_width.min(legend_area.width),
+                height: 1,
+            };
+            line.render(item_area, buf);
+        }
+    }
+
+    /// Splits the horizontal legend into rows that fit in `width`, keeping at
+    /// most `max_rows` of them. Returns the rows, each a list of entries with
+    /// their text, and the number of entries that did not fit.
+    ///
+    /// When entries are left out, the last row is shortened until the
+    /// [`more_indicator`](Self::more_indicator) fits after it.
+    fn horizontal_legend_rows(
+        &self,
+        width: u16,
+        max_rows: u16,
+        entries: &Entries,
+    ) -> (Vec<Vec<(Entry, String)>>, usize) {
+        let total = entries.total;
+        let text_width = PieChart::text_width;
+        // The spacing after the last item of a row may hang over the edge.
+        let row_width = |items: &[(Entry, String)]| {
+            items
+                .iter()
+                .map(|(_, text)| text_width(text))
+                .fold(0u16, u16::saturating_add)
+                .saturating_sub(2)
+        };
+
+        let mut rows: Vec<Vec<(Entry, String)>> = Vec::new();
+        let mut row = Vec::new();
+        for &entry in &entries.legend {
+            let mut text = self.format_legend_text(entry.index, total, "  ");
+            if text_width(&text).saturating_sub(2) > width {
+                text = self.fit_legend_text(entry.index, total, width) + "  ";
+            }
+            let new_width = row_width(&row)
+                .saturating_add(2)
+                .saturating_add(text_width(&text).saturating_sub(2));
+            if !row.is_empty() && new_width > width {
+                rows.push(std::mem::take(&mut row));
+            }
+            row.push((entry, text));
+        }
+        if !row.is_empty() {
+            rows.push(row);
+        }
+
+        let max_rows = usize::from(max_rows);
+        if rows.len() <= max_rows {
+            return (rows, 0);
+        }
+        let mut hidden: usize = rows.drain(max_rows..).map(|row| row.len()).sum();
+        if let Some(last) = rows.last_mut() {
+            while !last.is_empty()
+                && row_width(last)
+                    .saturating_add(2)
+                    .saturating_add(text_width(&Self::more_indicator(hidden)))
+                    > width
+            {
+                last.pop();
+                hidden += 1;
+            }
+        }
+        (rows, hidden)
+    }
+
+    /// Text shown in place of legend entries that did not fit.
+    fn more_indicator(hidden: usize) -> String {
+        format!("+{hidden} more")
+    }
+
+    fn render_table_legend(
+        &self,
+        buf: &mut Buffer,
+        legend_area: Rect,
+        entries: &Entries,
+        state: &PieChartState,
+    ) {
+        let rows = self.table_rows(entries);
+        let mut widths = PieChart::table_column_widths(&rows);
+        // Narrow the label column to fit, shortening the labels in it
+        let overflow = usize::from(self.table_width(entries).saturating_sub(legend_area.width));
+        if overflow > 0 {
+            widths[1] = match self.options.legend_truncation {
+                LegendTruncation::Hide => 0,
+                _ => widths[1].saturating_sub(overflow),
+            };
+        }
+
+        for (row, (entry, cells)) in rows.iter().enumerate() {
+            #[allow(clippy::cast_possible_truncation)]
+            let y_offset = row as u16;
+
+            if y_offset >= legend_area.height {
+                break;
+            }
+
+            let mut cells = cells.clone();
+            if overflow > 0 {
+                cells[1] = self
+                    .options
+                    .legend_truncation
+                    .truncate(&cells[1], widths[1])
+                    .into_owned()
+                    .into();
+            }
+            let text = self.format_table_row(&cells, &widths);
+            let text_width = PieChart::text_width(&text);
+            let x_pos = self.calculate_aligned_x(legend_area, text_width);
+            let style = entry.map_or(self.options.legend_table.style, |entry| {
+                self.entry_legend_style(entry, state)
+            });
+
+            let item_area = Rect {
+                x: x_pos,
+                y: legend_area.y + y_offset,
+                width: text_width.min(legend_area.width),
+                height: 1,
+            };
+            Line::styled(text, style).render(item_area, buf);
+        }
+    }
+
+    /// Returns the cells of every table legend row (marker, label, value and
+    /// percent), with the entry each row belongs to. The header and footer
+    /// rows have no entry.
+    fn table_rows(&self, entries: &Entries) -> Vec<(Option<Entry>, [Cow<'_, str>; 4])> {
+        let table = &self.options.legend_table;
+        let total = entries.total;
+        let mut rows = Vec::new();
+        if table.header {
+            rows.push((
+                None,
+                ["".into(), "Label".into(), "Value".into(), "%".into()],
+            ));
+        }
+        for &entry in &entries.legend {
+            let percent = PieChart::value_percent(entry.value, total);
+            rows.push((
+                Some(entry),
+                [
+                    self.slice_marker(entry.index),
+                    self.slices.label(entry.index).into(),
+                    table.format_value(entry.value).into(),
+                    format!("{percent:.1}%").into(),
+                ],
+            ));
+        }
+        if table.footer {
+            let percent = if total > 0.0 { 100.0 } else { 0.0 };
+            rows.push((
+                None,
+                [
+                    "".into(),
+                    "Total".into(),
+                    table.format_value(total).into(),
+                    format!("{percent:.1}%").into(),
+                ],
+            ));
+        }
+        rows
+    }
+
+    /// Lays out one table row: marker and label left-aligned, then the value
+    /// and percent columns right-aligned when shown. Every row of a table has
+    /// the same width.
+    fn format_table_row(&self, cells: &[Cow<'_, str>; 4], widths: &[usize; 4]) -> String {
+        // Padding by display width keeps wide characters aligned
+        let pad = |text: &str, width: usize| " ".repeat(width.saturating_sub(text.width()));
+        let [marker, label, value, percent] = cells;
+        let value = if self.options.legend_table.values {
+            format!("  {}{value}", pad(value, widths[2]))
+        } else {
+            String::new()
+        };
+        let percent = if self.options.show_percentages {
+            format!("  {}{percent}", pad(percent, widths[3]))
+        } else {
+            String::new()
+        };
+        format!(
+            "{marker}{} {label}{}{value}{percent}",
+            pad(marker, widths[0]),
+            pad(label, widths[1])
+        )
+    }
+
+    fn table_width(&self, entries: &Entries) -> u16 {
+        let rows = self.table_rows(entries);
+        let widths = PieChart::table_column_widths(&rows);
+        rows.first().map_or(0, |(_, cells)| {
+            PieChart::text_width(&self.format_table_row(cells, &widths))
+        })
+    }
+
+    /// Returns a borrowing copy of the chart with an automatic legend
+    /// position resolved for `area`, and the split of `area` between its pie
+    /// and legend.
+    fn resolve_layout(
+        &self,
+        area: Rect,
+        entries: &Entries,
+    ) -> (PieChart<'_, &D>, (Rect, Option<Rect>)) {
+        if self.options.show_legend && self.options.legend_position == LegendPosition::Auto {
+            let chart = self.fit_legend(area, entries);
+            let layout = chart.split_layout(area, entries, false);
+            (chart, layout)
+        } else {
+            (self.by_ref(), self.split_layout(area, entries, true))
+        }
+    }
+
+    /// Splits `area` between the pie and a legend at a fixed position.
+    ///
+    /// With `enforce_min_size`, areas too small to share get no legend. An
+    /// automatic position has already been fitted to the area and skips it.
+    #[allow(clippy::too_many_lines)]
+    fn split_layout(
+        &self,
+        area: Rect,
+        entries: &Entries,
+        enforce_min_size: bool,
+    ) -> (Rect, Option<Rect>) {
+        if !self.options.show_legend || self.options.legend_position == LegendPosition::Auto {
+            return (area, None);
+        }
+        // Overlays take no room from the pie, so they fit in any area
+        if self.options.legend_position.is_overlay() {
+            return (area, Some(self.layout_overlay(area, entries)));
+        }
+        if enforce_min_size && (area.width < 20 || area.height < 10) {
+            return (area, None);
+        }
+
+        // Vertical layout uses Left/Right positions, Horizontal layout uses Top/Bottom
+        match (self.options.legend_position, self.options.legend_layout) {
+            // Left/Right with Vertical or Table layout - proper vertical stacking on sides
+            (
+                LegendPosition::Left | LegendPosition::Right,
+                LegendLayout::Vertical | LegendLayout::Table,
+            ) => {
+                let config = &self.options.legend_config;
+                let (frame_width, _, title_width) = self.legend_frame_size();
+                let legend_width = self
+                    .calculate_legend_width(entries)
+                    .max(title_width)
+                    .saturating_add(frame_width)
+                    .min(area.width / config.vertical_max_ratio.max(1))
+                    .max(config.vertical_min_width);
+                let is_left = matches!(self.options.legend_position, LegendPosition::Left);
+                PieChart::layout_horizontal_split(
+                    area,
+                    legend_width,
+                    is_left,
+                    &self.options.legend_config,
+                )
+            }
+            // Top/Bottom with Horizontal layout - single row at top/bottom
+            (LegendPosition::Top | LegendPosition::Bottom, LegendLayout::Horizontal) => {
+                let is_top = matches!(self.options.legend_position, LegendPosition::Top);
+                let legend_height = self.calculate_horizontal_legend_height(area.width, entries);
+                PieChart::layout_vertical_split(
+                    area,
+                    legend_height,
+                    is_top,
+                    &self.options.legend_config,
+                )
+            }
+            // Fallback: use horizontal layout for incompatible combinations
+            (LegendPosition::Left | LegendPosition::Right, LegendLayout::Horizontal) => {
+                // Horizontal layout on sides - allocate limited width
+                let (frame_width, _, title_width) = self.legend_frame_size();
+                let legend_width = self
+                    .calculate_legend_horizontal_width(entries)
+                    .max(title_width)
+                    .saturating_add(frame_width)
+                    .min(
+                        (area.width * (PieChart::LEGEND_HORIZONTAL_MAX_RATIO - 1))
+                            / PieChart::LEGEND_HORIZONTAL_MAX_RATIO,
+                    )
+                    .min(self.options.legend_config.horizontal_max_width);
+                let is_left = matches!(self.options.legend_position, LegendPosition::Left);
+                PieChart::layout_horizontal_split(
+                    area,
+                    legend_width,
+                    is_left,
+                    &self.options.legend_config,
+                )
+            }
+            (LegendPosition::Top | LegendPosition::Bottom, LegendLayout::Vertical) => {
+                // Vertical layout at top/bottom - use a multi-column grid with minimal height
+                let legend_height = self.calculate_vertical_grid_height(area.width, entries);
+                let is_top = matches!(self.options.legend_position, LegendPosition::Top);
+                PieChart::layout_vertical_split(
+                    area,
+                    legend_height,
+                    is_top,
+                    &self.options.legend_config,
+                )
+            }
+            (LegendPosition::Top | LegendPosition::Bottom, LegendLayout::Table) => {
+                // Table at top/bottom - one line per row, including header and footer
+                #[allow(clippy::cast_possible_truncation)]
+                let rows = self.table_rows(entries).len() as u16;
+                let config = &self.options.legend_config;
+                let (_, frame_height, _) = self.legend_frame_size();
+                let legend_height = rows
+                    .saturating_add(config.padding)
+                    .min(config.vertical_max_height)
+                    .saturating_add(frame_height);
+                let is_top = matches!(self.options.legend_position, LegendPosition::Top);
+                PieChart::layout_vertical_split(
+                    area,
+                    legend_height,
+                    is_top,
+                    &self.options.legend_config,
+                )
+            }
+            // Overlay and automatic positions are handled above
+            (
+                LegendPosition::TopLeft
+                | LegendPosition::TopRight
+                | LegendPosition::BottomLeft
+                | LegendPosition::BottomRight
+                | LegendPosition::Auto,
+                _,
+            ) => (area, None),
+        }
+    }
+
+    /// Returns a borrowing copy of the chart with an automatic legend
+    /// position resolved for `area`.
+    ///
+    /// The side matching the area's shape is tried first: the right of a
+    /// wide area, where a round pie leaves spare columns, and the bottom of a
+    /// tall one. The legend goes on the first side where all of its entries
+    /// fit without scrolling or wrapping away, and the pie keeps at least
+    /// [`AUTO_MIN_PIE_SIZE`](PieChart::AUTO_MIN_PIE_SIZE). Failing that the
+    /// percentages and bars are dropped and both sides tried again, and
+    /// failing that too the legend is hidden.
+    fn fit_legend(&self, area: Rect, entries: &Entries) -> PieChart<'_, &D> {
+        let mut chart = self.by_ref();
+        // Cells are about twice as tall as they are wide
+        let sides = if area.width >= area.height.saturating_mul(2) {
+            [LegendPosition::Right, LegendPosition::Bottom]
+        } else {
+            [LegendPosition::Bottom, LegendPosition::Right]
+        };
+        for abbreviated in [false, true] {
+            if abbreviated {
+                chart.options.show_percentages = false;
+                chart.options.legend_bar_width = 0;
+            }
+            for side in sides {
+                chart.options.legend_position = side;
+                if chart.legend_fits(area, entries) {
+                    return chart;
+                }
+            }
+        }
+        chart.options.show_legend = false;
+        chart
+    }
+
+    /// Returns whether the legend at its current position shows every entry
+    /// in `area` and leaves the pie room enough.
+    fn legend_fits(&self, area: Rect, entries: &Entries) -> bool {
+        let (pie_area, Some(legend_area)) = self.split_layout(area, entries, false) else {
+            return false;
+        };
+        let (min_width, min_height) = PieChart::AUTO_MIN_PIE_SIZE;
+        if pie_area.width < min_width || pie_area.height < min_height {
+            return false;
+        }
+        let (frame_width, _, _) = self.legend_frame_size();
+        let inner_width = legend_area.width.saturating_sub(frame_width);
+        let grid = self.options.legend_layout == LegendLayout::Vertical
+            && matches!(
+                self.options.legend_position,
+                LegendPosition::Top | LegendPosition::Bottom
+            );
+        let (max_width, grid_columns) = match self.options.legend_layout {
+            LegendLayout::Horizontal => {
+                let (_, hidden) = self.horizontal_legend_rows(
+                    inner_width,
+                    PieChart::LEGEND_HORIZONTAL_MAX_ROWS,
+                    entries,
+                );
+                if hidden > 0 {
+                    return false;
+                }
+                (legend_area.width, None)
+            }
+            _ => (
+                u16::MAX,
+                grid.then(|| self.legend_grid_columns(inner_width, entries)),
+            ),
+        };
+        let (width, height) = self.legend_size(max_width, grid_columns, entries);
+        width <= legend_area.width && height <= legend_area.height
+    }
+
+    /// Returns a copy of the chart that borrows the data source, the block
+    /// and the legend title.
+    fn by_ref(&self) -> PieChart<'_, &D> {
+        PieChart {
+            slices: &self.slices,
+            options: ChartOptions {
+                block: self.options.block.as_deref().map(MaybeBorrowed::Borrowed),
+                legend_title: self
+                    .options
+                    .legend_title
+                    .as_deref()
+                    .map(MaybeBorrowed::Borrowed),
+                ..self.options
+            },
+        }
+    }
+
+    /// Returns the corner of `area` an overlay legend is drawn in, padding
+    /// included. The pie keeps the whole area.
+    fn layout_overlay(&self, area: Rect, entries: &Entries) -> Rect {
+        // Padding on both sides
+        let padding = self.options.legend_config.padding.saturating_mul(2);
+        let (width, height) = self.legend_size(area.width.saturating_sub(padding), None, entries);
+        let width = width.saturating_add(padding).min(area.width);
+        let height = height.saturating_add(padding).min(area.height);
+        let (left, top) = match self.options.legend_position {
+            LegendPosition::TopLeft => (true, true),
+            LegendPosition::TopRight => (false, true),
+            LegendPosition::BottomLeft => (true, false),
+            _ => (false, false),
+        };
+        Rect {
+            x: if left { area.x } else { area.right() - width },
+            y: if top { area.y } else { area.bottom() - height },
+            width,
+            height,
+        }
+    }
+
+    /// Returns the height of a wrapping horizontal legend at the top or
+    /// bottom: one line per row, up to
+    /// [`LEGEND_HORIZONTAL_MAX_ROWS`](PieChart::LEGEND_HORIZONTAL_MAX_ROWS),
+    /// the padding above and below, and the legend title and border.
+    fn calculate_horizontal_legend_height(&self, available_width: u16, entries: &Entries) -> u16 {
+        let padding = self.options.legend_config.padding;
+        let (frame_width, frame_height, _) = self.legend_frame_size();
+        let (rows, _) = self.horizontal_legend_rows(
+            available_width.saturating_sub(padding.saturating_mul(2).saturating_add(frame_width)),
+            PieChart::LEGEND_HORIZONTAL_MAX_ROWS,
+            entries,
+        );
+        #[allow(clippy::cast_possible_truncation)]
+        let rows = rows.len().max(1) as u16;
+        rows.saturating_add(padding.saturating_mul(2))
+            .saturating_add(frame_height)
+    }
+
+    fn calculate_vertical_grid_height(&self, available_width: u16, entries: &Entries) -> u16 {
+        // For vertical layout at top/bottom, use a grid as wide as the legend area
+        let config = &self.options.legend_config;
+        let (frame_width, frame_height, _) = self.legend_frame_size();
+        let columns = self.legend_grid_columns(
+            available_width
+                .saturating_sub(config.padding.saturating_mul(2).saturating_add(frame_width)),
+            entries,
+        );
+
+        #[allow(clippy::cast_possible_truncation)]
+        let num_items = entries.legend.len() as u16;
+
+        // Calculate rows: ceil(items / columns)
+        let rows = num_items.div_ceil(columns);
+        // Each row takes one line plus the item spacing, and padding is subtracted again by
+        // the split. A single row keeps a blank line below it.
+        let pitch = config.item_pitch();
+        rows.saturating_mul(pitch)
+            .saturating_add(config.padding)
+            .max(pitch.saturating_add(config.padding).saturating_add(1))
+            .min(config.vertical_max_height)
+            .saturating_add(frame_height)
+    }
+
+    fn calculate_legend_width(&self, entries: &Entries) -> u16 {
+        let total = entries.total;
+        let widths = entries
+            .legend
+            .iter()
+            .map(|entry| self.legend_item_width(entry.index, total));
+
+        let base = match self.options.legend_layout {
+            // For vertical layout, the column is as wide as the widest item.
+            LegendLayout::Vertical => widths.max().unwrap_or(0),
+            // For horizontal layout, all items sit on one row.
+            LegendLayout::Horizontal => widths.fold(0u16, u16::saturating_add),
+            // For table layout, every row is padded to the same width.
+            LegendLayout::Table => self.table_width(entries),
+        };
+
+        base.saturating_add(2)
+    }
+
+    fn calculate_legend_horizontal_width(&self, entries: &Entries) -> u16 {
+        let total = entries.total;
+        entries
+            .legend
+            .iter()
+            .map(|entry| self.legend_item_width(entry.index, total))
+            .fold(0u16, u16::saturating_add)
+            .saturating_add(2)
+    }
+
+    /// Returns the width and height the legend needs to show every entry,
+    /// with its title and border, in at most `max_width` cells. A vertical
+    /// legend is measured as a single column, or as a grid when given a
+    /// number of columns; a horizontal legend wraps to fit.
+    fn legend_size(
+        &self,
+        max_width: u16,
+        grid_columns: Option<u16>,
+        entries: &Entries,
+    ) -> (u16, u16) {
+        #[allow(clippy::cast_possible_truncation)]
+        let count = entries.legend.len() as u16;
+        let (frame_width, frame_height, title_width) = self.legend_frame_size();
+        let max_width = max_width.saturating_sub(frame_width);
+
+        let (width, height) = if count == 0 {
+            (0, 0)
+        } else {
+            match self.options.legend_layout {
+                LegendLayout::Vertical => {
+                    // A grid gets whole columns, gaps included, so that they
+                    // all fit again.
+                    let column_width = self.calculate_legend_width(entries);
+                    let (width, rows) = match grid_columns {
+                        Some(columns) => (
+                            column_width.saturating_mul(columns),
+                            count.div_ceil(columns),
+                        ),
+                        None => (column_width.saturating_sub(4), count),
+                    };
+                    let config = self.options.legend_config;
+                    let height = rows
+                        .saturating_mul(config.item_pitch())
+                        .saturating_sub(config.item_spacing);
+                    (width, height)
+                }
+                LegendLayout::Horizontal => {
+                    let (rows, hidden) = self.horizontal_legend_rows(
+                        max_width,
+                        PieChart::LEGEND_HORIZONTAL_MAX_ROWS,
+                        entries,
+                    );
+                    let width = if hidden > 0 {
+                        max_width
+                    } else {
+                        rows.iter()
+                            .map(|row| {
+                                let width: usize = row.iter().map(|(_, text)| text.width()).sum();
+                                u16::try_from(width).unwrap_or(u16::MAX).saturating_sub(2)
+                            })
+                            .max()
+                            .unwrap_or(0)
+                    };
+                    #[allow(clippy::cast_possible_truncation)]
+                    (width, rows.len() as u16)
+                }
+                #[allow(clippy::cast_possible_truncation)]
+                LegendLayout::Table => (
+                    self.table_width(entries),
+                    self.table_rows(entries).len() as u16,
+                ),
+            }
+        };
+
+        (
+            width
+                .max(title_width)
+                .min(max_width)
+                .saturating_add(frame_width),
+            height.saturating_add(frame_height),
+        )
+    }
+
+    #[allow(clippy::similar_names)]
+    fn render_piechart_braille(
+        &self,
+        layout: (Rect, Option<Rect>),
+        entries: &Entries,
+        buf: &mut Buffer,
+        state: &mut PieChartState,
+    ) {
+        let (pie_area, legend_area_opt) = layout;
+        let total = entries.total;
+
+        // Calculate the center and radius of the pie chart
+        let (center_x_chars, center_y_chars, radius_chars) = self.pie_geometry(pie_area);
+
+        // Each character cell has 2x4 braille dots
+        let center_x_dots = u32::from(center_x_chars) * 2;
+        let center_y_dots = u32::from(center_y_chars) * 4;
+
+        // Calculate radius in dots
+        // Braille dots are equally spaced in physical screen space because:
+        // - Character cells are ~2:1 (height:width)
+        // - But braille has 2 horizontal dots and 4 vertical dots per character
+        // - So: horizontal spacing = W/2, vertical spacing = 2W/4 = W/2 (equal!)
+        let radius = u32::from(radius_chars) * 2;
+
+        // Create a 2D array to store which slice each braille dot belongs to
+        let width_dots = u32::from(pie_area.width) * 2;
+        let height_dots = u32::from(pie_area.height) * 4;
+
+        let mut dot_slices: Vec<Vec<Option<usize>>> =
+            vec![vec![None; width_dots as usize]; height_dots as usize];
+
+        // Only the dots in both the array and the box around the pie
+        let scan_x =
+            center_x_dots.saturating_sub(radius)..(center_x_dots + radius + 1).min(width_dots);
+        let scan_y =
+            center_y_dots.saturating_sub(radius)..(center_y_dots + radius + 1).min(height_dots);
+
+        // Calculate slice assignments for each dot
+        let mut cumulative_percent = 0.0;
+        for (slice_idx, entry) in entries.pie.iter().enumerate() {
+            let percent = self.percentage(entry.index, total);
+            let (start_angle, end_angle, is_full_circle) =
+                PieChart::slice_angles(cumulative_percent, percent);
+            let pattern = self.slice_pattern(entry.index);
+
+            for dy in scan_y.clone() {
+                for dx in scan_x.clone() {
+                    let rel_x = f64::from(dx) - f64::from(center_x_dots);
+                    let rel_y = f64::from(dy) - f64::from(center_y_dots);
+
+                    // No aspect ratio compensation needed for braille dots
+                    // They're already equally spaced in physical screen space
+                    let distance = (rel_x * rel_x + rel_y * rel_y).sqrt();
+
+                    #[allow(clippy::cast_possible_truncation)]
+                    let covered =
+                        distance <= f64::from(radius) && pattern.covers_dot(dx as u16, dy as u16);
+                    if covered {
+                        let angle = rel_y.atan2(rel_x);
+                        if is_full_circle
+                            || PieChart::is_angle_in_slice(angle, start_angle, end_angle)
+                        {
+                            dot_slices[dy as usize][dx as usize] = Some(slice_idx);
+                        }
+                    }
+                }
+            }
+
+            cumulative_percent += percent;
+        }
+
+        // Convert dot assignments to braille characters
+        for char_y in 0..pie_area.height {
+            for char_x in 0..pie_area.width {
+                let base_dot_x = u32::from(char_x) * 2;
+                let base_dot_y = u32::from(char_y) * 4;
+
+                let mut pattern = 0u32;
+                let mut slice_colors: Vec<(usize, u32)> = Vec::new();
+
+                for (dx, dy, bit) in PieChart::BRAILLE_DOTS {
+                    let dot_x = base_dot_x + u32::from(dx);
+                    let dot_y = base_dot_y + u32::from(dy);
+
+                    if dot_y < height_dots && dot_x < width_dots {
+                        if let Some(slice_idx) = dot_slices[dot_y as usize][dot_x as usize] {
+                            pattern |= bit;
+                            // Track which slice and how many dots
+                            if let Some(entry) =
+                                slice_colors.iter_mut().find(|(idx, _)| *idx == slice_idx)
+                            {
+                                entry.1 += 1;
+                            } else {
+                                slice_colors.push((slice_idx, 1));
+                            }
+                        }
+                    }
+                }
+
+                if pattern > 0 {
+                    // Use the color of the slice with the most dots in this character
+                    if let Some((slice_idx, _)) = slice_colors.iter().max_by_key(|(_, count)| count)
+                    {
+                        let braille_char = char::from_u32(0x2800 + pattern).unwrap_or('⠀');
+                        let entry = entries.pie[*slice_idx];
+                        let style = self.slice_style(entry.index);
+
+                        let cell = &mut buf[(pie_area.x + char_x, pie_area.y + char_y)];
+                        cell.set_char(braille_char).set_style(style);
+                    }
+                }
+            }
+        }
+
+        // Draw legend if enabled
+        if let Some(legend_area) = legend_area_opt {
+            self.render_legend(buf, legend_area, entries, state);
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+#[allow(unnameable_test_items)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pie_slice_new() {
+        let slice = PieSlice::new("Test", 50.0, Color::Red);
+        assert_eq!(slice.label(), "Test");
+        assert_eq!(slice.value(), 50.0);
+        assert_eq!(slice.color(), Color::Red);
+        assert!(slice.has_color());
+    }
+
+    #[test]
+    fn pie_slice_auto() {
+        let slice = PieSlice::auto("Test", 50.0);
+        assert_eq!(slice.label(), "Test");
+        assert_eq!(slice.value(), 50.0);
+        assert_eq!(slice.color(), Color::Reset);
+        assert!(!slice.has_color());
+    }
+
+    #[test]
+    fn piechart_new() {
+        let slices = vec![
+            PieSlice::new("A", 30.0, Color::Red),
+            PieSlice::new("B", 70.0, Color::Blue),
+        ];
+        let piechart = PieChart::new(slices.clone());
+        assert_eq!(piechart.slices, slices);
+    }
+
+    #[test]
+    fn piechart_default() {
+        let piechart = PieChart::default();
+        assert!(piechart.slices.is_empty());
+        assert!(piechart.options.show_legend);
+        assert!(piechart.options.show_percentages);
+    }
+
+    #[test]
+    fn piechart_slices() {
+        let slices = vec![PieSlice::new("Test", 100.0, Color::Green)];
+        let piechart = PieChart::default().slices(slices.clone());
+        assert_eq!(piechart.slices, slices);
+    }
+
+    #[test]
+    fn piechart_style() {
+        let style = Style::default().fg(Color::Red);
+        let piechart = PieChart::default().style(style);
+        assert_eq!(piechart.options.style, style);
+    }
+
+    #[test]
+    fn piechart_show_legend() {
+        let piechart = PieChart::default().show_legend(false);
+        assert!(!piechart.options.show_legend);
+    }
+
+    #[test]
+    fn piechart_show_percentages() {
+        let piechart = PieChart::default().show_percentages(false);
+        assert!(!piechart.options.show_percentages);
+    }
+
+    #[test]
+    fn piechart_pie_char() {
+        let piechart = PieChart::default().pie_char('█');
+        assert_eq!(piechart.options.pie_char, '█');
+    }
+
+    #[test]
+    fn piechart_total_value() {
+        let slices = vec![
+            PieSlice::new("A", 30.0, Color::Red),
+            PieSlice::new("B", 70.0, Color::Blue),
+        ];
+        let piechart = PieChart::new(slices);
+        assert_eq!(piechart.entries().total, 100.0);
+    }
+
+    #[test]
+    fn piechart_percentage() {
+        let slices = vec![
+            PieSlice::new("A", 30.0, Color::Red),
+            PieSlice::new("B", 70.0, Color::Blue),
+        ];
+        let piechart = PieChart::new(slices);
+        assert_eq!(piechart.percentage(0, piechart.entries().total), 30.0);
+    }
+
+    // Render tests - using macros for common patterns
+    render_empty_test!(piechart_render_empty_area, PieChart::default());
+
+    render_with_size_test!(
+        piechart_render_with_block,
+        {
+            let slices = vec![PieSlice::new("Test", 100.0, Color::Red)];
+            PieChart::new(slices).block(Block::bordered())
+        },
+        width: 20,
+        height: 10
+    );
+
+    render_test!(
+        piechart_render_basic,
+        {
+            let slices = vec![
+                PieSlice::new("Rust", 45.0, Color::Red),
+                PieSlice::new("Go", 30.0, Color::Blue),
+                PieSlice::new("Python", 25.0, Color::Green),
+            ];
+            PieChart::new(slices)
+        },
+        Rect::new(0, 0, 40, 20)
+    );
+
+    #[test]
+    fn piechart_styled_trait() {
+        use ratatui::style::Stylize;
+        let piechart = PieChart::default().red();
+        assert_eq!(piechart.options.style.fg, Some(Color::Red));
+    }
+
+    #[test]
+    fn piechart_with_multiple_slices() {
+        let slices = vec![
+            PieSlice::new("A", 25.0, Color::Red),
+            PieSlice::new("B", 25.0, Color::Blue),
+            PieSlice::new("C", 25.0, Color::Green),
+            PieSlice::new("D", 25.0, Color::Yellow),
+        ];
+        let piechart = PieChart::new(slices);
+        assert_eq!(piechart.entries().total, 100.0);
+    }
+
+    // Using render macro for the visual test
+    render_with_size_test!(
+        piechart_multi_slice_render,
+        {
+            let slices = vec![
+                PieSlice::new("A", 25.0, Color::Red),
+                PieSlice::new("B", 25.0, Color::Blue),
+                PieSlice::new("C", 25.0, Color::Green),
+                PieSlice::new("D", 25.0, Color::Yellow),
+            ];
+            PieChart::new(slices)
+        },
+        width: 50,
+        height: 30
+    );
+
+    #[test]
+    fn piechart_zero_values() {
+        let slices = vec![
+            PieSlice::new("A", 0.0, Color::Red),
+            PieSlice::new("B", 0.0, Color::Blue),
+        ];
+        let piechart = PieChart::new(slices);
+        assert_eq!(piechart.entries().total, 0.0);
+    }
+
+    #[test]
+    fn piechart_method_chaining() {
+        use ratatui::widgets::Block;
+
+        let slices = vec![PieSlice::new("Test", 100.0, Color::Red)];
+        let piechart = PieChart::new(slices)
+            .show_legend(true)
+            .show_percentages(true)
+            .pie_char('█')
+            .block(Block::bordered().title("Test"))
+            .style(Style::default().fg(Color::White));
+
+        assert!(piechart.options.show_legend);
+        assert!(piechart.options.show_percentages);
+        assert_eq!(piechart.options.pie_char, '█');
+        assert!(piechart.options.block.is_some());
+        assert_eq!(piechart.options.style.fg, Some(Color::White));
+    }
+
+    #[test]
+    fn piechart_custom_symbols() {
+        use crate::symbols;
+
+        let piechart = PieChart::default().pie_char(symbols::PIE_CHAR_BLOCK);
+        assert_eq!(piechart.options.pie_char, '█');
+
+        let piechart = PieChart::default().pie_char(symbols::PIE_CHAR_CIRCLE);
+        assert_eq!(piechart.options.pie_char, '◉');
+
+        let piechart = PieChart::default().pie_char(symbols::PIE_CHAR_SQUARE);
+        assert_eq!(piechart.options.pie_char, '■');
+    }
+
+    #[test]
+    fn piechart_is_angle_in_slice() {
+        use std::f64::consts::PI;
+
+        // Test angle in range
+        assert!(PieChart::is_angle_in_slice(PI / 4.0, 0.0, PI / 2.0));
+
+        // Test angle outside range
+        assert!(!PieChart::is_angle_in_slice(PI, 0.0, PI / 2.0));
+
+        // Test wrap around
+        assert!(PieChart::is_angle_in_slice(0.1, 1.5 * PI, 0.5));
+    }
+
+    // --- Resolution ---
+
+    matches_test!(
+        piechart_resolution_standard,
+        PieChart::default()
+            .resolution(Resolution::Standard)
+            .options
+            .resolution,
+        Resolution::Standard
+    );
+    matches_test!(
+        piechart_resolution_braille,
+        PieChart::default()
+            .resolution(Resolution::Braille)
+            .options
+            .resolution,
+        Resolution::Braille
+    );
+    matches_test!(
+        piechart_high_resolution_true,
+        PieChart::default().high_resolution(true).options.resolution,
+        Resolution::Braille
+    );
+    matches_test!(
+        piechart_high_resolution_false,
+        PieChart::default()
+            .high_resolution(false)
+            .options
+            .resolution,
+        Resolution::Standard
+    );
+
+    #[test]
+    fn resolution_parse_and_display() {
+        assert_eq!("Braille".parse(), Ok(Resolution::Braille));
+        assert_eq!(Resolution::Standard.to_string(), "standard");
+        assert_eq!(Resolution::ALL, [Resolution::Standard, Resolution::Braille]);
+        assert!("hd".parse::<Resolution>().is_err());
+    }
+
+    #[test]
+    fn pie_radius_parse_and_display() {
+        for radius in [PieRadius::Fit, PieRadius::Fixed(8), PieRadius::Max(12)] {
+            assert_eq!(radius.to_string().parse(), Ok(radius));
+        }
+        assert_eq!("Fixed: 4".parse(), Ok(PieRadius::Fixed(4)));
+        assert_eq!(PieRadius::Max(3).to_string(), "max:3");
+        assert!("fixed".parse::<PieRadius>().is_err());
+        assert!("max:-1".parse::<PieRadius>().is_err());
+        assert!("huge:3".parse::<PieRadius>().is_err());
+    }
+
+    // --- Legend position / layout / alignment setters ---
+
+    matches_test!(
+        piechart_legend_position_left,
+        PieChart::default()
+            .legend_position(LegendPosition::Left)
+            .options
+            .legend_position,
+        LegendPosition::Left
+    );
+    matches_test!(
+        piechart_legend_position_right,
+        PieChart::default()
+            .legend_position(LegendPosition::Right)
+            .options
+            .legend_position,
+        LegendPosition::Right
+    );
+    matches_test!(
+        piechart_legend_position_top,
+        PieChart::default()
+            .legend_position(LegendPosition::Top)
+            .options
+            .legend_position,
+        LegendPosition::Top
+    );
+    matches_test!(
+        piechart_legend_position_bottom,
+        PieChart::default()
+            .legend_position(LegendPosition::Bottom)
+            .options
+            .legend_position,
+        LegendPosition::Bottom
+    );
+    matches_test!(
+        piechart_legend_layout_horizontal,
+        PieChart::default()
+            .legend_layout(LegendLayout::Horizontal)
+            .options
+            .legend_layout,
+        LegendLayout::Horizontal
+    );
+    matches_test!(
+        piechart_legend_layout_vertical,
+        PieChart::default()
+            .legend_layout(LegendLayout::Vertical)
+            .options
+            .legend_layout,
+        LegendLayout::Vertical
+    );
+    matches_test!(
+        piechart_legend_alignment_left,
+        PieChart::default()
+            .legend_alignment(LegendAlignment::Left)
+            .options
+            .legend_alignment,
+        LegendAlignment::Left
+    );
+    matches_test!(
+        piechart_legend_alignment_center,
+        PieChart::default()
+            .legend_alignment(LegendAlignment::Center)
+            .options
+            .legend_alignment,
+        LegendAlignment::Center
+    );
+    matches_test!(
+        piechart_legend_alignment_right,
+        PieChart::default()
+            .legend_alignment(LegendAlignment::Right)
+            .options
+            .legend_alignment,
+        LegendAlignment::Right
+    );
+
+    // --- Palette ---
+
+    matches_test!(
+        piechart_palette_default,
+        PieChart::default().options.palette,
+        Palette::Categorical
+    );
+    matches_test!(
+        piechart_palette_okabe_ito,
+        PieChart::default()
+            .palette(Palette::OkabeIto)
+            .options
+            .palette,
+        Palette::OkabeIto
+    );
+
+    #[test]
+    fn piechart_slice_color_uses_palette_for_auto_slices() {
+        let slices = vec![
+            PieSlice::auto("A", 10.0),
+            PieSlice::new("B", 10.0, Color::White),
+            PieSlice::auto("C", 10.0),
+        ];
+        let chart = PieChart::new(slices.clone()).palette(Palette::Tableau);
+        assert_eq!(chart.slice_color(0), palette::TABLEAU[0]);
+        assert_eq!(chart.slice_color(1), Color::White);
+        assert_eq!(chart.slice_color(2), palette::TABLEAU[2]);
+    }
+
+    #[test]
+    fn piechart_slice_color_cycles_past_palette_end() {
+        let colors = [Color::Red, Color::Blue];
+        let slices: Vec<_> = (0..5).map(|_| PieSlice::auto("X", 1.0)).collect();
+        let chart = PieChart::new(slices.clone()).palette(Palette::Custom(&colors));
+        let resolved: Vec<_> = slices
+            .iter()
+            .enumerate()
+            .map(|(i, _)| chart.slice_color(i))
+            .collect();
+        assert_eq!(
+            resolved,
+            [Color::Red, Color::Blue, Color::Red, Color::Blue, Color::Red]
+        );
+    }
+
+    render_test!(
+        piechart_render_auto_colored_slices,
+        PieChart::new(vec![
+            PieSlice::auto("Rust", 45.0),
+            PieSlice::auto("Go", 30.0),
+            PieSlice::auto("Python", 25.0),
+        ])
+        .palette(Palette::Pastel),
+        Rect::new(0, 0, 60, 20)
+    );
+
+    // --- Fill patterns ---
+
+    #[test]
+    fn piechart_auto_patterns_setter() {
+        assert!(!PieChart::default().options.auto_patterns);
+        assert!(
+            PieChart::default()
+                .auto_patterns(true)
+                .options
+                .auto_patterns
+        );
+    }
+
+    #[test]
+    fn piechart_slice_pattern_precedence() {
+        let slices = vec![
+            PieSlice::new("A", 10.0, Color::Red),
+            PieSlice::new("B", 10.0, Color::Red).pattern(FillPattern::Dotted),
+        ];
+        let plain = PieChart::new(slices.clone());
+        assert_eq!(plain.slice_pattern(0), FillPattern::Solid);
+        assert_eq!(plain.slice_pattern(1), FillPattern::Dotted);
+
+        let auto = PieChart::new(slices.clone()).auto_patterns(true);
+        assert_eq!(auto.slice_pattern(0), FillPattern::nth(0));
+        assert_eq!(auto.slice_pattern(1), FillPattern::Dotted);
+    }
+
+    #[test]
+    fn piechart_slice_char_solid_uses_pie_char() {
+        let slices = vec![PieSlice::new("A", 10.0, Color::Red)];
+        let chart = PieChart::new(slices.clone()).pie_char('█');
+        assert_eq!(chart.slice_char(0), '█');
+    }
+
+    #[test]
+    fn piechart_format_legend_text_shows_pattern_glyph() {
+        let slices = vec![PieSlice::new("Rust", 50.0, Color::Red).pattern(FillPattern::LightShade)];
+        let chart = PieChart::new(slices.clone());
+        let text = chart.format_legend_text(0, 100.0, "");
+        assert!(text.starts_with('░'));
+        assert!(!text.contains(chart.options.legend_marker));
+    }
+
+    #[test]
+    fn piechart_render_patterns_standard() {
+        let chart = PieChart::new(vec![
+            PieSlice::new("A", 50.0, Color::Red),
+            PieSlice::new("B", 50.0, Color::Blue),
+        ])
+        .auto_patterns(true)
+        .show_legend(false);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 40, 20));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        let hatch = FillPattern::nth(1).glyph().unwrap().to_string();
+        assert!(buffer.content.iter().any(|c| c.symbol() == hatch));
+        assert!(buffer.content.iter().any(|c| c.symbol() == "●"));
+    }
+
+    #[test]
+    fn piechart_render_patterns_braille_raise_fewer_dots() {
+        fn raised_dots(chart: &PieChart) -> u32 {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 30, 15));
+            Widget::render(chart, buffer.area, &mut buffer);
+            buffer
+                .content
+                .iter()
+                .filter_map(|c| c.symbol().chars().next())
+                .filter(|c| ('\u{2800}'..='\u{28FF}').contains(c))
+                .map(|c| (u32::from(c) - 0x2800).count_ones())
+                .sum()
+        }
+        let solid = PieChart::new(vec![PieSlice::new("A", 100.0, Color::Red)])
+            .resolution(Resolution::Braille)
+            .show_legend(false);
+        let shaded = PieChart::new(vec![
+            PieSlice::new("A", 100.0, Color::Red).pattern(FillPattern::LightShade)
+        ])
+        .resolution(Resolution::Braille)
+        .show_legend(false);
+        assert!(raised_dots(&shaded) > 0);
+        assert!(raised_dots(&shaded) < raised_dots(&solid) / 2);
+    }
+
+    // --- Per-slice styles ---
+
+    #[test]
+    fn pie_slice_style_and_stylize() {
+        use ratatui::style::{Modifier, Stylize};
+        let slice = PieSlice::new("A", 1.0, Color::Red).style(Style::default().bg(Color::Black));
+        assert_eq!(Styled::style(&slice).bg, Some(Color::Black));
+
+        let slice = PieSlice::new("A", 1.0, Color::Red).bold();
+        assert!(Styled::style(&slice).add_modifier.contains(Modifier::BOLD));
+    }
+
+    #[test]
+    fn piechart_slice_style_patches_color() {
+        use ratatui::style::Modifier;
+        let slices = vec![
+            PieSlice::new("A", 1.0, Color::Red).style(Style::default().add_modifier(Modifier::DIM)),
+            PieSlice::new("B", 1.0, Color::Red).style(Style::default().fg(Color::Cyan)),
+        ];
+        let chart = PieChart::new(slices.clone());
+        let style = chart.slice_style(0);
+        assert_eq!(style.fg, Some(Color::Red));
+        assert!(style.add_modifier.contains(Modifier::DIM));
+        // A foreground in the slice style wins over the slice color
+        assert_eq!(chart.slice_style(1).fg, Some(Color::Cyan));
+    }
+
+    #[test]
+    fn piechart_slice_legend_style_patches_slice_style() {
+        use ratatui::style::Modifier;
+        let slices = vec![PieSlice::new("A", 1.0, Color::Red)
+            .style(Style::default().bg(Color::Black))
+            .legend_style(Style::default().add_modifier(Modifier::REVERSED))];
+        let chart = PieChart::new(slices.clone());
+        let style = chart.slice_legend_style(0);
+        assert_eq!(style.fg, Some(Color::Red));
+        assert_eq!(style.bg, Some(Color::Black));
+        assert!(style.add_modifier.contains(Modifier::REVERSED));
+        assert!(!chart
+            .slice_style(0)
+            .add_modifier
+            .contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn piechart_render_applies_slice_style() {
+        use ratatui::style::Modifier;
+        let chart = PieChart::new(vec![PieSlice::new("Only", 100.0, Color::Green).style(
+            Style::default()
+                .bg(Color::Black)
+                .add_modifier(Modifier::BOLD),
+        )])
+        .show_legend(false);
+        for resolution in [Resolution::Standard, Resolution::Braille] {
+            let chart = chart.clone().resolution(resolution);
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 30, 15));
+            Widget::render(&chart, buffer.area, &mut buffer);
+            let cell = buffer
+                .content
+                .iter()
+                .find(|c| !c.symbol().trim().is_empty())
+                .expect("pie cells are drawn");
+            assert_eq!(cell.fg, Color::Green);
+            assert_eq!(cell.bg, Color::Black);
+            assert!(cell.modifier.contains(Modifier::BOLD));
+        }
+    }
+
+    // --- Per-slice symbol overrides ---
+
+    #[test]
+    fn piechart_slice_char_precedence() {
+        use crate::symbols;
+        let slices = vec![
+            PieSlice::new("A", 1.0, Color::Red),
+            PieSlice::new("B", 1.0, Color::Red).pattern(FillPattern::LightShade),
+            PieSlice::new("C", 1.0, Color::Red)
+                .pattern(FillPattern::LightShade)
+                .pie_char(symbols::PIE_CHAR_CROSS),
+        ];
+        let chart = PieChart::new(slices.clone()).pie_char(symbols::PIE_CHAR_BLOCK);
+        assert_eq!(chart.slice_char(0), symbols::PIE_CHAR_BLOCK);
+        assert_eq!(chart.slice_char(1), symbols::PIE_CHAR_LIGHT);
+        assert_eq!(chart.slice_char(2), symbols::PIE_CHAR_CROSS);
+    }
+
+    #[test]
+    fn piechart_slice_marker_precedence() {
+        use crate::symbols;
+        let slices = vec![
+            PieSlice::new("A", 1.0, Color::Red),
+            PieSlice::new("B", 1.0, Color::Red).pattern(FillPattern::LightShade),
+            PieSlice::new("C", 1.0, Color::Red)
+                .pattern(FillPattern::LightShade)
+                .legend_marker(symbols::LEGEND_MARKER_CROSS),
+        ];
+        let chart = PieChart::new(slices.clone()).legend_marker(symbols::LEGEND_MARKER_CIRCLE);
+        assert_eq!(chart.slice_marker(0), symbols::LEGEND_MARKER_CIRCLE);
+        assert_eq!(chart.slice_marker(1), "░");
+        assert_eq!(chart.slice_marker(2), symbols::LEGEND_MARKER_CROSS);
+    }
+
+    #[test]
+    fn piechart_render_slice_symbol_overrides() {
+        use crate::symbols;
+        let chart = PieChart::new(vec![
+            PieSlice::new("Ok", 50.0, Color::Green),
+            PieSlice::new("Errors", 50.0, Color::Red)
+                .pie_char(symbols::PIE_CHAR_CROSS)
+                .legend_marker(symbols::LEGEND_MARKER_CROSS),
+        ]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 60, 20));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        let symbols_drawn: String = buffer
+            .content
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect();
+        assert!(symbols_drawn.contains(symbols::PIE_CHAR));
+        assert!(symbols_drawn.contains(symbols::PIE_CHAR_CROSS));
+        assert!(symbols_drawn.contains("✖ Errors"));
+    }
+
+    // --- Themes ---
+
+    #[test]
+    fn piechart_theme_applies_options() {
+        let theme = PieChartTheme::dark()
+            .legend_position(LegendPosition::Bottom)
+            .legend_layout(LegendLayout::Horizontal)
+            .legend_alignment(LegendAlignment::Center)
+            .auto_patterns(true);
+        let chart = PieChart::default().theme(&theme);
+        assert_eq!(chart.options.style, theme.style);
+        assert_eq!(chart.options.pie_char, theme.pie_char);
+        assert_eq!(chart.options.legend_marker, theme.legend_marker);
+        assert_eq!(chart.options.palette, Palette::Tableau);
+        assert!(chart.options.auto_patterns);
+        assert_eq!(chart.options.legend_position, LegendPosition::Bottom);
+        assert_eq!(chart.options.legend_layout, LegendLayout::Horizontal);
+        assert_eq!(chart.options.legend_alignment, LegendAlignment::Center);
+        assert!(chart.options.block.is_some());
+    }
+
+    #[test]
+    fn piechart_theme_keeps_existing_block() {
+        let theme = PieChartTheme::dark();
+        let block = Block::bordered().title("Mine");
+        let chart = PieChart::default().block(block.clone()).theme(&theme);
+        assert_eq!(chart.options.block.as_deref(), Some(&block));
+    }
+
+    #[test]
+    fn piechart_theme_without_border_adds_no_block() {
+        let chart = PieChart::default().theme(&PieChartTheme::default());
+        assert!(chart.options.block.is_none());
+    }
+
+    #[test]
+    fn piechart_theme_then_override() {
+        let chart = PieChart::default()
+            .theme(&PieChartTheme::minimal_ascii())
+            .pie_char('*');
+        assert_eq!(chart.options.pie_char, '*');
+        assert_eq!(chart.options.legend_marker, "*");
+    }
+
+    render_test!(
+        piechart_render_minimal_ascii_theme,
+        PieChart::new(vec![
+            PieSlice::auto("Rust", 45.0),
+            PieSlice::auto("Go", 30.0),
+        ])
+        .theme(&PieChartTheme::minimal_ascii()),
+        Rect::new(0, 0, 60, 20)
+    );
+
+    // --- Value validation / ValuePolicy ---
+
+    /// Renders `chart` in a `width` by `height` buffer, with `state` if one
+    /// is given, and returns the symbols of all cells.
+    fn render_to_string<D: PieDataSource>(
+        chart: &PieChart<'_, D>,
+        width: u16,
+        height: u16,
+        state: Option<&mut PieChartState>,
+    ) -> String {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, width, height));
+        match state {
+            Some(state) => StatefulWidget::render(chart, buffer.area, &mut buffer, state),
+            None => Widget::render(chart, buffer.area, &mut buffer),
+        }
+        buffer
+            .content
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect()
+    }
+
+    fn slices_with_invalid() -> Vec<PieSlice<'static>> {
+        vec![
+            PieSlice::new("Rust", 60.0, Color::Red),
+            PieSlice::new("Broken", f64::NAN, Color::Yellow),
+            PieSlice::new("Go", 40.0, Color::Blue),
+        ]
+    }
+
+    #[test]
+    fn piechart_validate_reports_first_invalid_slice() {
+        let chart = PieChart::new(vec![
+            PieSlice::auto("A", 1.0),
+            PieSlice::auto("B", -2.0),
+            PieSlice::auto("C", f64::INFINITY),
+        ]);
+        let err = chart.validate().unwrap_err();
+        assert_eq!(err.index(), 1);
+        assert_eq!(err.label(), "B");
+        assert_eq!(err.kind(), ValueErrorKind::Negative);
+        assert!(PieChart::default().validate().is_ok());
+    }
+
+    #[test]
+    fn piechart_try_new() {
+        assert!(PieChart::try_new(vec![PieSlice::auto("A", 0.0)]).is_ok());
+        let err = PieChart::try_new(slices_with_invalid()).unwrap_err();
+        assert_eq!(err.label(), "Broken");
+    }
+
+    #[test]
+    fn piechart_value_policy_default_is_clamp() {
+        assert_eq!(PieChart::default().options.value_policy, ValuePolicy::Clamp);
+        assert_eq!("skip".parse(), Ok(ValuePolicy::Skip));
+    }
+
+    #[test]
+    fn piechart_value_policy_clamp() {
+        let chart = PieChart::new(slices_with_invalid());
+        assert_eq!(chart.entries().total, 100.0);
+        assert_eq!(chart.entries().pie.len(), 3);
+        let text = render_to_string(&chart, 60, 20, None);
+        assert!(text.contains("Broken 0.0%"));
+        assert!(text.contains("Rust 60.0%"));
+    }
+
+    #[test]
+    fn piechart_value_policy_skip() {
+        let chart = PieChart::new(slices_with_invalid()).value_policy(ValuePolicy::Skip);
+        assert_eq!(chart.entries().total, 100.0);
+        let indices: Vec<_> = chart.entries().pie.iter().map(|e| e.index).collect();
+        assert_eq!(indices, [0, 2]);
+        let text = render_to_string(&chart, 60, 20, None);
+        assert!(!text.contains("Broken"));
+        assert!(text.contains("Go 40.0%"));
+    }
+
+    #[test]
+    fn piechart_value_policy_error_renders_nothing() {
+        let chart = PieChart::new(slices_with_invalid()).value_policy(ValuePolicy::Error);
+        assert_eq!(chart.entries().pie.len(), 0);
+        assert!(render_to_string(&chart, 60, 20, None).trim().is_empty());
+
+        let valid =
+            PieChart::new(vec![PieSlice::auto("Rust", 1.0)]).value_policy(ValuePolicy::Error);
+        assert!(render_to_string(&valid, 60, 20, None).contains("Rust"));
+    }
+
+    #[test]
+    fn piechart_value_policy_error_renders_placeholder() {
+        let chart = PieChart::new(slices_with_invalid())
+            .value_policy(ValuePolicy::Error)
+            .placeholder(Placeholder::new("Bad data").show_legend(true));
+        let text = render_to_string(&chart, 60, 20, None);
+        assert!(text.contains("Bad data"));
+        // No slice is drawn, so the legend has no entries to list
+        assert!(!text.contains("Rust"));
+    }
+
+    // --- Placeholder ---
+
+    #[test]
+    fn piechart_placeholder_text_for_empty_chart() {
+        assert!(render_to_string(&PieChart::default(), 60, 20, None)
+            .trim()
+            .is_empty());
+        let chart = PieChart::default().placeholder(Placeholder::default());
+        assert!(render_to_string(&chart, 60, 20, None).contains("No data"));
+    }
+
+    #[test]
+    fn piechart_placeholder_ring() {
+        let chart = PieChart::default().placeholder(Placeholder::new("").ring(Color::DarkGray));
+        let text = render_to_string(&chart, 60, 20, None);
+        assert!(text.contains(symbols::PIE_CHAR_DOT));
+        assert!(!text.contains("No data"));
+
+        let braille = chart.resolution(Resolution::Braille);
+        assert!(render_to_string(&braille, 60, 20, None)
+            .chars()
+            .any(|c| ('\u{2801}'..='\u{28FF}').contains(&c)));
+    }
+
+    #[test]
+    fn piechart_placeholder_zero_total_legend() {
+        let slices = vec![PieSlice::auto("Rust", 0.0), PieSlice::auto("Go", 0.0)];
+        let hidden = PieChart::new(slices.clone()).placeholder(Placeholder::default());
+        let text = render_to_string(&hidden, 60, 20, None);
+        assert!(text.contains("No data"));
+        assert!(!text.contains("Rust"));
+
+        let shown = PieChart::new(slices).placeholder(Placeholder::default().show_legend(true));
+        let text = render_to_string(&shown, 60, 20, None);
+        assert!(text.contains("No data"));
+        assert!(text.contains("Rust 0.0%"));
+        assert!(text.contains("Go 0.0%"));
+    }
+
+    #[test]
+    fn piechart_placeholder_not_drawn_with_data() {
+        let chart =
+            PieChart::new(vec![PieSlice::auto("Rust", 1.0)]).placeholder(Placeholder::default());
+        assert!(!render_to_string(&chart, 60, 20, None).contains("No data"));
+    }
+
+    // --- Constructors ---
+
+    #[test]
+    fn piechart_from_iterator() {
+        let chart: PieChart = ["A", "B"]
+            .into_iter()
+            .map(|label| PieSlice::auto(label, 1.0))
+            .collect();
+        assert_eq!(chart.slices.len(), 2);
+        assert_eq!(chart.slices[1].label(), "B");
+    }
+
+    #[test]
+    fn piechart_from_values_is_auto_colored() {
+        let chart = PieChart::from_values([("Rust", 45.0), ("Go", 30.0)]);
+        assert_eq!(chart.slices.len(), 2);
+        assert!(chart.slices.iter().all(|s| !s.has_color()));
+        assert_eq!(chart.entries().total, 75.0);
+    }
+
+    #[test]
+    fn piechart_from_colored_tuples() {
+        let data = [("Rust", 45.0, Color::Red), ("Go", 30.0, Color::Blue)];
+        let chart = PieChart::from(&data[..]);
+        assert_eq!(chart.slices[0], PieSlice::new("Rust", 45.0, Color::Red));
+        assert_eq!(chart.slices[1].color(), Color::Blue);
+    }
+
+    #[test]
+    fn piechart_from_maps_sorted_by_key() {
+        let hash: HashMap<String, f64> = [("zig", 1.0), ("ada", 2.0), ("go", 3.0)]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect();
+        let btree: BTreeMap<String, f64> = hash.clone().into_iter().collect();
+
+        let from_hash = PieChart::from(&hash);
+        let from_btree = PieChart::from(&btree);
+        let labels: Vec<_> = from_hash.slices.iter().map(PieSlice::label).collect();
+        assert_eq!(labels, ["ada", "go", "zig"]);
+        assert_eq!(from_hash, from_btree);
+    }
+
+    // --- Data sources ---
+
+    struct Metrics {
+        names: [&'static str; 3],
+        counts: [u32; 3],
+    }
+
+    impl PieDataSource for Metrics {
+        fn len(&self) -> usize {
+            self.counts.len()
+        }
+
+        fn label(&self, index: usize) -> &str {
+            self.names[index]
+        }
+
+        fn value(&self, index: usize) -> f64 {
+            f64::from(self.counts[index])
+        }
+
+        fn style(&self, index: usize) -> Style {
+            if index == 0 {
+                Style::new().fg(Color::Red)
+            } else {
+                Style::new()
+            }
+        }
+    }
+
+    #[test]
+    fn piechart_custom_source_matches_slices() {
+        let metrics = Metrics {
+            names: ["Rust", "Go", "C"],
+            counts: [45, 30, 25],
+        };
+        let slices = vec![
+            PieSlice::new("Rust", 45.0, Color::Red),
+            PieSlice::auto("Go", 30.0),
+            PieSlice::auto("C", 25.0),
+        ];
+        let mut from_source = Buffer::empty(Rect::new(0, 0, 60, 20));
+        let mut from_slices = from_source.clone();
+        Widget::render(
+            PieChart::from_source(&metrics),
+            from_source.area,
+            &mut from_source,
+        );
+        Widget::render(PieChart::new(slices), from_slices.area, &mut from_slices);
+        assert_eq!(from_source, from_slices);
+    }
+
+    /// Counts the values read from it.
+    struct CountingSource {
+        len: usize,
+        reads: std::cell::Cell<usize>,
+    }
+
+    impl PieDataSource for CountingSource {
+        fn len(&self) -> usize {
+            self.len
+        }
+
+        fn label(&self, _index: usize) -> &'static str {
+            "slice"
+        }
+
+        fn value(&self, _index: usize) -> f64 {
+            self.reads.set(self.reads.get() + 1);
+            1.0
+        }
+    }
+
+    #[test]
+    fn piechart_render_reads_source_linearly() {
+        let reads = |len, resolution| {
+            let source = CountingSource {
+                len,
+                reads: std::cell::Cell::new(0),
+            };
+            let chart = PieChart::from_source(&source)
+                .value_policy(ValuePolicy::Error)
+                .resolution(resolution);
+            render_to_string(&chart, 60, 20, None);
+            source.reads.get()
+        };
+        for resolution in [Resolution::Standard, Resolution::Braille] {
+            // Twice the slices must not take four times the reads
+            assert!(reads(200, resolution) < 3 * reads(100, resolution));
+        }
+    }
+
+    #[test]
+    fn piechart_borrowed_slices_as_source() {
+        let slices = vec![PieSlice::auto("Rust", 45.0), PieSlice::auto("Go", 30.0)];
+        let owned = PieChart::new(slices.clone()).resolution(Resolution::Braille);
+        let borrowed = PieChart::from_source(slices.as_slice()).resolution(Resolution::Braille);
+        assert_eq!(
+            render_to_string(&owned, 60, 20, None),
+            render_to_string(&borrowed, 60, 20, None)
+        );
+    }
+
+    #[test]
+    fn piechart_data_source_keeps_options() {
+        let slices = vec![PieSlice::auto("Rust", 45.0)];
+        let chart = PieChart::default()
+            .show_legend(false)
+            .pie_char('x')
+            .data_source(&slices);
+        assert!(!chart.options.show_legend);
+        assert_eq!(chart.options.pie_char, 'x');
+        assert_eq!(chart.slices.len(), 1);
+    }
+
+    // --- Slice order ---
+
+    #[test]
+    fn piechart_slice_order_sorts_stably() {
+        let chart = PieChart::new(vec![
+            PieSlice::auto("Go", 30.0),
+            PieSlice::auto("Rust", 45.0),
+            PieSlice::auto("C", 30.0),
+            PieSlice::auto("Python", 25.0),
+        ]);
+        let expected = [
+            (SliceOrder::default(), ["Go", "Rust", "C", "Python"]),
+            (SliceOrder::ValueDescending, ["Rust", "Go", "C", "Python"]),
+            (SliceOrder::ValueAscending, ["Python", "Go", "C", "Rust"]),
+            (SliceOrder::Label, ["C", "Go", "Python", "Rust"]),
+        ];
+        for (order, labels) in expected {
+            let chart = chart.clone().slice_order(order);
+            let entries = chart.entries().pie;
+            let drawn: Vec<_> = entries
+                .iter()
+                .map(|e| chart.slices[e.index].label)
+                .collect();
+            assert_eq!(drawn, labels, "{order}");
         }
     }
 
-    fn layout_vertical_split(
-        area: Rect,
-        legend_height: u16,
-        legend_on_top: bool,
-    ) -> (Rect, Option<Rect>) {
-        if area.height <= legend_height {
-            return (area, None);
-        }
+    #[test]
+    fn piechart_slice_order_keeps_colors_by_index() {
+        let chart = PieChart::new(vec![
+            PieSlice::auto("Go", 30.0),
+            PieSlice::auto("Rust", 45.0),
+            PieSlice::auto("C", 30.0),
+            PieSlice::auto("Python", 25.0),
+        ])
+        .slice_order(SliceOrder::Label)
+        .auto_patterns(true);
+        let indices: Vec<_> = chart.entries().pie.iter().map(|e| e.index).collect();
+        assert_eq!(indices, [2, 0, 3, 1]);
+        // "Rust" is drawn last but keeps the color and pattern of index 1
+        assert_eq!(chart.slice_color(1), Palette::default().color(1));
+        assert_eq!(chart.slice_pattern(1), FillPattern::nth(1));
+
+        // A slice whose rank changes keeps its color
+        let before = PieChart::new(vec![PieSlice::auto("A", 1.0), PieSlice::auto("B", 2.0)])
+            .slice_order(SliceOrder::ValueDescending);
+        let after = PieChart::new(vec![PieSlice::auto("A", 3.0), PieSlice::auto("B", 2.0)])
+            .slice_order(SliceOrder::ValueDescending);
+        assert_eq!(before.entries().pie[1].index, 0);
+        assert_eq!(after.entries().pie[0].index, 0);
+        assert_eq!(before.slice_style(0), after.slice_style(0));
+    }
 
-        let pie_height = area
-            .height
-            .saturating_sub(legend_height + Self::LEGEND_SPACING);
+    #[test]
+    fn piechart_legend_order_insertion_keeps_colors() {
+        let chart = PieChart::new(vec![
+            PieSlice::auto("Go", 30.0),
+            PieSlice::auto("Rust", 45.0),
+            PieSlice::auto("C", 30.0),
+        ])
+        .slice_order(SliceOrder::ValueDescending)
+        .legend_order(LegendOrder::Insertion);
+        let indices: Vec<_> = chart.entries().legend.iter().map(|e| e.index).collect();
+        assert_eq!(indices, [0, 1, 2]);
+        let indices: Vec<_> = chart.entries().pie.iter().map(|e| e.index).collect();
+        assert_eq!(indices, [1, 0, 2]);
+    }
 
-        if legend_on_top {
-            (
-                Rect {
-                    x: area.x,
-                    y: area.y + legend_height + Self::LEGEND_SPACING,
-                    width: area.width,
-                    height: pie_height,
-                },
-                Some(Rect {
-                    x: area.x + Self::LEGEND_PADDING,
-                    y: area.y + Self::LEGEND_PADDING,
-                    width: area.width.saturating_sub(Self::LEGEND_PADDING * 2),
-                    height: legend_height.saturating_sub(Self::LEGEND_PADDING),
-                }),
-            )
-        } else {
-            (
-                Rect {
-                    x: area.x,
-                    y: area.y,
-                    width: area.width,
-                    height: pie_height,
-                },
-                Some(Rect {
-                    x: area.x + Self::LEGEND_PADDING,
-                    y: area.y + pie_height + Self::LEGEND_SPACING,
-                    width: area.width.saturating_sub(Self::LEGEND_PADDING * 2),
-                    height: legend_height.saturating_sub(Self::LEGEND_PADDING),
-                }),
-            )
-        }
+    #[test]
+    fn piechart_slice_order_renders_legend_in_order() {
+        let chart = PieChart::new(vec![
+            PieSlice::auto("Go", 30.0),
+            PieSlice::auto("Rust", 45.0),
+            PieSlice::auto("Python", 25.0),
+        ])
+        .slice_order(SliceOrder::ValueDescending);
+        let text = render_to_string(&chart, 60, 20, None);
+        let rust = text.find("Rust").unwrap();
+        let go = text.find("Go").unwrap();
+        let python = text.find("Python").unwrap();
+        assert!(rust < go && go < python);
     }
 
-    fn calculate_legend_width(&self) -> u16 {
-        let total = self.total_value();
-        let widths = self.slices.iter().map(|s| self.legend_item_width(s, total));
+    #[test]
+    fn piechart_slice_order_braille_keeps_slice_colors() {
+        let chart = PieChart::new(vec![
+            PieSlice::new("Small", 10.0, Color::Red),
+            PieSlice::new("Large", 90.0, Color::Blue),
+        ])
+        .slice_order(SliceOrder::ValueDescending)
+        .resolution(Resolution::Braille)
+        .show_legend(false);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 40, 20));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let count = |color| buffer.content.iter().filter(|c| c.fg == color).count();
+        assert!(count(Color::Blue) > count(Color::Red));
+    }
 
-        let base = match self.legend_layout {
-            // For vertical layout, the column is as wide as the widest item.
-            LegendLayout::Vertical => widths.max().unwrap_or(0),
-            // For horizontal layout, all items sit on one row.
-            LegendLayout::Horizontal => widths.fold(0u16, u16::saturating_add),
+    // --- Grid legend ---
+
+    #[test]
+    fn piechart_grid_legend_flow() {
+        let chart = PieChart::new(
+            ["A", "B", "C", "D", "E"]
+                .map(|label| PieSlice::auto(label, 20.0))
+                .to_vec(),
+        )
+        .legend_position(LegendPosition::Bottom)
+        .show_percentages(false)
+        .legend_columns(3);
+        // The (x, y) of each label's marker
+        let positions = |flow| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 60, 20));
+            Widget::render(chart.clone().legend_flow(flow), buffer.area, &mut buffer);
+            ["A", "B", "C", "D", "E"].map(|label| {
+                let pos = buffer
+                    .area
+                    .positions()
+                    .find(|&pos| {
+                        buffer[pos].symbol() == "■" && buffer[(pos.x + 2, pos.y)].symbol() == label
+                    })
+                    .unwrap();
+                (pos.x, pos.y)
+            })
         };
 
-        base.saturating_add(2)
+        let row_major = positions(LegendFlow::RowMajor);
+        let rows = row_major.map(|p| p.1);
+        assert_eq!(rows[0], rows[1]);
+        assert_eq!(rows[1], rows[2]);
+        assert_eq!(rows[3], rows[0] + 2);
+        assert_eq!(row_major[3].0, row_major[0].0);
+        assert_eq!(row_major[4].0, row_major[1].0);
+
+        let column_major = positions(LegendFlow::ColumnMajor);
+        assert_eq!(column_major[0].0, column_major[1].0);
+        assert_eq!(column_major[1].1, column_major[0].1 + 2);
+        assert_eq!(column_major[2].1, column_major[0].1);
+        assert_eq!(
+            column_major[4].0,
+            column_major[0].0 + 2 * (column_major[2].0 - column_major[0].0)
+        );
     }
 
-    fn calculate_legend_horizontal_width(&self) -> u16 {
-        let total = self.total_value();
-        self.slices
+    #[test]
+    fn piechart_grid_legend_columns_fit_width() {
+        let chart = PieChart::new(
+            ["A", "B", "C", "D", "E"]
+                .map(|label| PieSlice::auto(label, 20.0))
+                .to_vec(),
+        )
+        .legend_position(LegendPosition::Bottom)
+        .show_percentages(false);
+        // Each column is "■ A" plus four cells of spacing
+        assert_eq!(chart.legend_grid_columns(21, &chart.entries()), 3);
+        assert_eq!(chart.legend_grid_columns(100, &chart.entries()), 5);
+        assert_eq!(chart.legend_grid_columns(0, &chart.entries()), 1);
+        assert_eq!(
+            chart
+                .clone()
+                .legend_columns(2)
+                .legend_grid_columns(100, &chart.entries()),
+            2
+        );
+
+        // Two rows per line of entries, less the spacing after the last
+        let chart = chart.legend_columns(2);
+        assert_eq!(
+            chart.calculate_vertical_grid_height(60, &chart.entries()),
+            3 * 2 + 1
+        );
+        let chart = chart.legend_columns(5);
+        assert_eq!(
+            chart.calculate_vertical_grid_height(60, &chart.entries()),
+            4
+        );
+    }
+
+    #[test]
+    fn piechart_grid_legend_counts_cut_off_rows() {
+        let labels: Vec<String> = (0..40).map(|i| format!("L{i:02}")).collect();
+        let chart: PieChart = labels
             .iter()
-            .map(|s| self.legend_item_width(s, total))
-            .fold(0u16, u16::saturating_add)
-            .saturating_add(2)
+            .map(|label| PieSlice::auto(label, 1.0))
+            .collect();
+        let chart = chart
+            .legend_layout(LegendLayout::Vertical)
+            .legend_position(LegendPosition::Bottom)
+            .legend_columns(0)
+            .show_percentages(false);
+        let text = render_to_string(&chart, 40, 30, None);
+        let shown = labels
+            .iter()
+            .filter(|label| text.contains(label.as_str()))
+            .count();
+        // Four rows of four fit; the last cell stands in for the rest
+        assert_eq!(shown, 15);
+        assert!(text.contains("■ L14    +25 more"));
     }
 
-    #[allow(clippy::similar_names)]
-    fn render_piechart_braille(&self, area: Rect, buf: &mut Buffer) {
-        // Calculate layout with legend positioning
-        let (pie_area, legend_area_opt) = self.calculate_layout(area);
+    #[test]
+    fn piechart_grid_legend_counts_cut_off_rows_column_major() {
+        let chart: PieChart = ["L0", "L1", "L2", "L3", "L4", "L5", "L6"]
+            .map(|label| PieSlice::auto(label, 1.0))
+            .into_iter()
+            .collect();
+        let chart = chart
+            .legend_columns(3)
+            .legend_flow(LegendFlow::ColumnMajor)
+            .show_percentages(false);
+        let area = Rect::new(0, 0, 24, 3);
+        let mut buffer = Buffer::empty(area);
+        chart.render_grid_legend(
+            &mut buffer,
+            area,
+            &chart.entries(),
+            &PieChartState::default(),
+        );
+        let line =
+            |y: u16| -> String { (0..area.width).map(|x| buffer[(x, y)].symbol()).collect() };
+        // Columns of three, cut to two rows a blank line apart: the bottom
+        // right cell stands in for the rest even though L6 comes last
+        assert_eq!(line(0), "■ L0    ■ L3    ■ L6    ");
+        assert_eq!(line(2), "■ L1    +3 more         ");
+    }
 
-        // Calculate the center and radius of the pie chart
-        let center_x_chars = pie_area.width / 2;
-        let center_y_chars = pie_area.height / 2;
+    // --- Wrapping horizontal legend ---
 
-        // Each character cell has 2x4 braille dots
-        let center_x_dots = center_x_chars * 2;
-        let center_y_dots = center_y_chars * 4;
+    #[test]
+    fn piechart_horizontal_legend_wraps_rows() {
+        let chart: PieChart = ["One", "Two", "Three", "Four", "Five"]
+            .map(|label| PieSlice::auto(label, 1.0))
+            .into_iter()
+            .collect();
+        let chart = chart
+            .legend_layout(LegendLayout::Horizontal)
+            .show_percentages(false);
+        // "■ One  ■ Two  ■ Three" is 21 cells wide
+        let (rows, hidden) = chart.horizontal_legend_rows(21, 5, &chart.entries());
+        let labels: Vec<Vec<_>> = rows
+            .iter()
+            .map(|row| row.iter().map(|(_, text)| text.trim()).collect())
+            .collect();
+        assert_eq!(
+            labels,
+            [vec!["■ One", "■ Two", "■ Three"], vec!["■ Four", "■ Five"]]
+        );
+        assert_eq!(hidden, 0);
+        let (rows, _) = chart.horizontal_legend_rows(20, 5, &chart.entries());
+        assert_eq!(rows.len(), 3);
 
-        // Calculate radius in dots
-        // Braille dots are equally spaced in physical screen space because:
-        // - Character cells are ~2:1 (height:width)
-        // - But braille has 2 horizontal dots and 4 vertical dots per character
-        // - So: horizontal spacing = W/2, vertical spacing = 2W/4 = W/2 (equal!)
-        let radius = (center_x_dots).min(center_y_dots).saturating_sub(2);
+        // Each row of entries, and the rows between legend and pie
+        assert_eq!(
+            chart.calculate_horizontal_legend_height(60, &chart.entries()),
+            3
+        );
+        assert_eq!(
+            chart.calculate_horizontal_legend_height(23, &chart.entries()),
+            4
+        );
+    }
 
-        // Create a 2D array to store which slice each braille dot belongs to
-        let width_dots = pie_area.width * 2;
-        let height_dots = pie_area.height * 4;
+    #[test]
+    fn piechart_horizontal_legend_more_indicator() {
+        let labels = [
+            "One", "Two", "Three", "Four", "Five", "Six", "Seven", "Eight",
+        ];
+        let chart: PieChart = labels
+            .map(|label| PieSlice::auto(label, 1.0))
+            .into_iter()
+            .collect();
+        let chart = chart
+            .legend_layout(LegendLayout::Horizontal)
+            .legend_position(LegendPosition::Bottom)
+            .show_percentages(false);
+        let (rows, hidden) = chart.horizontal_legend_rows(21, 2, &chart.entries());
+        assert_eq!(rows.len(), 2);
+        // Room is made on the last row for "  +N more"
+        let last: Vec<_> = rows[1].iter().map(|(_, text)| text.trim()).collect();
+        assert_eq!(last, ["■ Four"]);
+        assert_eq!(hidden, 4);
+        assert_eq!(
+            chart.calculate_horizontal_legend_height(12, &chart.entries()),
+            PieChart::LEGEND_HORIZONTAL_MAX_ROWS + 2
+        );
 
-        let mut dot_slices: Vec<Vec<Option<usize>>> =
-            vec![vec![None; width_dots as usize]; height_dots as usize];
+        let top = chart.clone().legend_position(LegendPosition::Top);
+        let text = render_to_string(&top, 60, 20, None);
+        assert!(text.contains("■ Eight"));
+        assert!(!text.contains("more"));
 
-        // Calculate slice assignments for each dot
-        let mut cumulative_percent = 0.0;
-        for (slice_idx, slice) in self.slices.iter().enumerate() {
-            let percent = self.percentage(slice);
-            let (start_angle, end_angle, is_full_circle) =
-                Self::slice_angles(cumulative_percent, percent);
+        // 18 cells leave room for two short entries per row, and three rows
+        let text = render_to_string(&chart, 20, 12, None);
+        assert!(text.contains("■ Five  +3 more"));
+    }
 
-            for dy in 0..height_dots {
-                for dx in 0..width_dots {
-                    let rel_x = f64::from(dx) - f64::from(center_x_dots);
-                    let rel_y = f64::from(dy) - f64::from(center_y_dots);
+    // --- Legend geometry ---
 
-                    // No aspect ratio compensation needed for braille dots
-                    // They're already equally spaced in physical screen space
-                    let distance = (rel_x * rel_x + rel_y * rel_y).sqrt();
+    #[test]
+    fn piechart_legend_config_defaults_match_layout() {
+        let chart = PieChart::new(
+            ["A", "B", "C", "D", "E"]
+                .map(|label| PieSlice::auto(label, 20.0))
+                .to_vec(),
+        )
+        .show_percentages(false);
+        let area = Rect::new(0, 0, 90, 20);
+        let (pie_area, legend_area) = chart.resolve_layout(area, &chart.entries()).1;
+        let legend_area = legend_area.unwrap();
+        assert_eq!(legend_area.width, 20);
+        assert_eq!(pie_area.width, 90 - 20 - 1);
+        assert_eq!((legend_area.y, legend_area.height), (1, 18));
+    }
 
-                    if distance <= f64::from(radius) {
-                        let angle = rel_y.atan2(rel_x);
-                        if is_full_circle || Self::is_angle_in_slice(angle, start_angle, end_angle)
-                        {
-                            dot_slices[dy as usize][dx as usize] = Some(slice_idx);
-                        }
-                    }
-                }
-            }
+    #[test]
+    fn piechart_legend_config_compact() {
+        let config = LegendConfig::new()
+            .vertical_min_width(5)
+            .spacing(0)
+            .padding(0)
+            .item_spacing(0);
+        let chart = PieChart::new(
+            ["A", "B", "C", "D", "E"]
+                .map(|label| PieSlice::auto(label, 20.0))
+                .to_vec(),
+        )
+        .show_percentages(false)
+        .legend_config(config);
+        let (pie_area, legend_area) = chart
+            .resolve_layout(Rect::new(0, 0, 90, 20), &chart.entries())
+            .1;
+        let legend_area = legend_area.unwrap();
+        assert_eq!(
+            (legend_area.width, legend_area.y, legend_area.height),
+            (7, 0, 20)
+        );
+        assert_eq!(pie_area.width, 83);
 
-            cumulative_percent += percent;
+        // Entries on consecutive lines
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 90, 20));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        for (y, label) in (0..).zip(["A", "B", "C"]) {
+            assert_eq!(buffer[(85, y)].symbol(), label);
         }
 
-        // Convert dot assignments to braille characters
-        for char_y in 0..pie_area.height {
-            for char_x in 0..pie_area.width {
-                let base_dot_x = char_x * 2;
-                let base_dot_y = char_y * 4;
-
-                // Braille pattern mapping (dots are numbered 1-8)
-                // Dot positions in a 2x4 grid:
-                // 1 4
-                // 2 5
-                // 3 6
-                // 7 8
-                let dot_positions = [
-                    (0, 0, 0x01), // dot 1
-                    (0, 1, 0x02), // dot 2
-                    (0, 2, 0x04), // dot 3
-                    (1, 0, 0x08), // dot 4
-                    (1, 1, 0x10), // dot 5
-                    (1, 2, 0x20), // dot 6
-                    (0, 3, 0x40), // dot 7
-                    (1, 3, 0x80), // dot 8
-                ];
+        let chart = chart.legend_position(LegendPosition::Bottom);
+        assert_eq!(
+            chart.calculate_vertical_grid_height(60, &chart.entries()),
+            2
+        );
+        let chart = chart.legend_layout(LegendLayout::Horizontal);
+        assert_eq!(
+            chart.calculate_horizontal_legend_height(200, &chart.entries()),
+            1
+        );
+    }
 
-                let mut pattern = 0u32;
-                let mut slice_colors: Vec<(usize, u32)> = Vec::new();
+    #[test]
+    fn piechart_legend_config_limits() {
+        let chart = PieChart::new(
+            ["A", "B", "C", "D", "E"]
+                .map(|label| PieSlice::auto(label, 20.0))
+                .to_vec(),
+        )
+        .show_percentages(false);
+        let bottom = chart
+            .clone()
+            .legend_position(LegendPosition::Bottom)
+            .legend_config(LegendConfig::new().vertical_max_height(3));
+        assert_eq!(
+            bottom.calculate_vertical_grid_height(60, &bottom.entries()),
+            3
+        );
 
-                for (dx, dy, bit) in dot_positions {
-                    let dot_x = base_dot_x + dx;
-                    let dot_y = base_dot_y + dy;
+        let entries = chart.entries();
+        let config = LegendConfig::new().vertical_min_width(50);
+        let (_, legend_area) = chart
+            .clone()
+            .legend_config(config)
+            .resolve_layout(Rect::new(0, 0, 90, 20), &entries)
+            .1;
+        assert_eq!(legend_area.unwrap().width, 50);
+        let config = LegendConfig::new()
+            .vertical_min_width(0)
+            .vertical_max_ratio(30);
+        let (_, legend_area) = chart
+            .legend_config(config)
+            .resolve_layout(Rect::new(0, 0, 90, 20), &entries)
+            .1;
+        assert_eq!(legend_area.unwrap().width, 3);
+    }
 
-                    if dot_y < height_dots && dot_x < width_dots {
-                        if let Some(slice_idx) = dot_slices[dot_y as usize][dot_x as usize] {
-                            pattern |= bit;
-                            // Track which slice and how many dots
-                            if let Some(entry) =
-                                slice_colors.iter_mut().find(|(idx, _)| *idx == slice_idx)
-                            {
-                                entry.1 += 1;
-                            } else {
-                                slice_colors.push((slice_idx, 1));
-                            }
-                        }
-                    }
+    #[test]
+    fn piechart_legend_config_extreme_values() {
+        let chart = PieChart::new(
+            ["A", "B", "C", "D", "E"]
+                .map(|label| PieSlice::auto(label, 20.0))
+                .to_vec(),
+        )
+        .legend_title("Legend")
+        .legend_separator(true)
+        .legend_border(BorderStyle::Rounded);
+        let configs = [
+            LegendConfig::new().padding(40000),
+            LegendConfig::new().item_spacing(40000),
+            LegendConfig::new().spacing(40000),
+            LegendConfig::new()
+                .vertical_max_ratio(u16::MAX)
+                .vertical_min_width(u16::MAX)
+                .horizontal_max_width(u16::MAX)
+                .vertical_max_height(u16::MAX)
+                .spacing(u16::MAX)
+                .padding(u16::MAX)
+                .item_spacing(u16::MAX),
+        ];
+        for config in configs {
+            for &position in LegendPosition::ALL {
+                for &layout in LegendLayout::ALL {
+                    let chart = chart
+                        .clone()
+                        .legend_config(config)
+                        .legend_position(position)
+                        .legend_layout(layout);
+                    // Must not overflow in layout or rendering
+                    render_to_string(&chart, 60, 20, None);
                 }
+            }
+        }
+    }
 
-                if pattern > 0 {
-                    // Use the color of the slice with the most dots in this character
-                    if let Some((slice_idx, _)) = slice_colors.iter().max_by_key(|(_, count)| count)
-                    {
-                        let braille_char = char::from_u32(0x2800 + pattern).unwrap_or('⠀');
-                        let color = self.slices[*slice_idx].color;
+    // --- Legend title and border ---
 
-                        let cell = &mut buf[(pie_area.x + char_x, pie_area.y + char_y)];
-                        cell.set_char(braille_char).set_fg(color);
-                    }
-                }
-            }
+    #[test]
+    fn piechart_legend_title_and_separator() {
+        let chart = PieChart::new(vec![
+            PieSlice::auto("Rust", 60.0),
+            PieSlice::auto("Go", 40.0),
+        ])
+        .legend_title(Title::new("Languages").left())
+        .legend_separator(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 60, 20));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        let row = |y| {
+            (40..60)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+        };
+        assert_eq!(row(1), "Languages           ");
+        assert_eq!(row(2), "─".repeat(20));
+        assert!(row(3).starts_with("■ Rust 60.0%"));
+        assert!(row(5).starts_with("■ Go 40.0%"));
+        assert!(buffer[(40, 1)].modifier.contains(Modifier::BOLD));
+
+        // A title at the bottom goes below the entries
+        let chart = chart.legend_title(Title::new("Total: 100").right().bottom());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 60, 20));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        let row = |y| {
+            (40..60)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+        };
+        assert!(row(1).starts_with("■ Rust 60.0%"));
+        assert_eq!(row(18), "          Total: 100");
+    }
+
+    #[test]
+    fn piechart_legend_border() {
+        let chart = PieChart::new(vec![
+            PieSlice::auto("Rust", 60.0),
+            PieSlice::auto("Go", 40.0),
+        ])
+        .legend_title(Title::new("Languages").left())
+        .legend_border(BorderStyle::Standard);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 60, 20));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        let row = |y| {
+            (40..60)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+        };
+        assert_eq!(row(1), format!("┌{}┐", "─".repeat(18)));
+        assert_eq!(row(2), "│Languages         │");
+        assert!(row(3).starts_with("│■ Rust 60.0%"));
+        assert_eq!(row(18), format!("└{}┘", "─".repeat(18)));
+    }
+
+    #[test]
+    fn piechart_legend_frame_adds_layout_rows() {
+        let chart = PieChart::new(
+            ["A", "B", "C", "D", "E"]
+                .map(|label| PieSlice::auto(label, 20.0))
+                .to_vec(),
+        )
+        .legend_position(LegendPosition::Bottom)
+        .show_percentages(false);
+        let grid_height = chart.calculate_vertical_grid_height(60, &chart.entries());
+        let chart = chart.legend_title("Letters").legend_separator(true);
+        assert_eq!(
+            chart.calculate_vertical_grid_height(60, &chart.entries()),
+            grid_height + 2
+        );
+        let chart = chart.legend_border(BorderStyle::Rounded);
+        assert_eq!(
+            chart.calculate_vertical_grid_height(60, &chart.entries()),
+            grid_height + 4
+        );
+
+        let chart = chart.legend_layout(LegendLayout::Horizontal);
+        assert_eq!(
+            chart.calculate_horizontal_legend_height(200, &chart.entries()),
+            3 + 4
+        );
+
+        // A long title widens a legend beside the pie
+        let chart = PieChart::new(vec![PieSlice::auto("A", 1.0)])
+            .legend_title("A rather long legend title");
+        let (_, legend_area) = chart
+            .resolve_layout(Rect::new(0, 0, 90, 20), &chart.entries())
+            .1;
+        assert_eq!(legend_area.unwrap().width, 26);
+    }
+
+    // --- Overlay legend ---
+
+    #[test]
+    fn piechart_overlay_keeps_pie_area() {
+        let chart = PieChart::new(vec![
+            PieSlice::auto("Rust", 60.0),
+            PieSlice::auto("Go", 40.0),
+        ]);
+        let area = Rect::new(0, 0, 60, 20);
+        let expected = [
+            (LegendPosition::TopLeft, Rect::new(0, 0, 14, 5)),
+            (LegendPosition::TopRight, Rect::new(46, 0, 14, 5)),
+            (LegendPosition::BottomLeft, Rect::new(0, 15, 14, 5)),
+            (LegendPosition::BottomRight, Rect::new(46, 15, 14, 5)),
+        ];
+        for (position, legend) in expected {
+            let chart = chart.clone().legend_position(position);
+            let (pie_area, legend_area) = chart.resolve_layout(area, &chart.entries()).1;
+            assert_eq!(pie_area, area);
+            assert_eq!(legend_area, Some(legend), "{position}");
         }
 
-        // Draw legend if enabled
-        if let Some(legend_area) = legend_area_opt {
-            self.render_legend(buf, legend_area);
+        let bottom_left = chart
+            .clone()
+            .legend_position(LegendPosition::BottomLeft)
+            .legend_layout(LegendLayout::Horizontal)
+            .legend_config(LegendConfig::new().padding(0));
+        let (_, legend_area) = bottom_left.resolve_layout(area, &bottom_left.entries()).1;
+        assert_eq!(legend_area, Some(Rect::new(0, 19, 24, 1)));
+
+        // Overlays are kept in areas too small to split
+        let small = Rect::new(0, 0, 18, 8);
+        let top_right = chart.legend_position(LegendPosition::TopRight);
+        let (pie_area, legend_area) = top_right.resolve_layout(small, &top_right.entries()).1;
+        assert_eq!(pie_area, small);
+        assert_eq!(legend_area, Some(Rect::new(4, 0, 14, 5)));
+    }
+
+    #[test]
+    fn piechart_overlay_background() {
+        let chart = PieChart::new(vec![
+            PieSlice::auto("Rust", 60.0),
+            PieSlice::auto("Go", 40.0),
+        ])
+        .legend_position(LegendPosition::TopRight);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 60, 20));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        let full_pie = buffer.clone();
+
+        let chart = chart.legend_background(Style::new().bg(Color::Black));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 60, 20));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        let row = |y| {
+            (46..60)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+        };
+        assert_eq!(row(1), " ■ Rust 60.0% ");
+        assert_eq!(row(3), " ■ Go 40.0%   ");
+        for position in Rect::new(46, 0, 14, 5).positions() {
+            assert_eq!(buffer[position].bg, Color::Black);
         }
+        // The pie is drawn at full size underneath
+        assert_eq!(buffer[(20, 10)], full_pie[(20, 10)]);
+        assert!(row(4).trim().is_empty());
     }
-}
 
-#[cfg(test)]
-#[allow(clippy::float_cmp)]
-#[allow(unnameable_test_items)]
-mod tests {
-    use super::*;
+    // --- Pie radius and alignment ---
 
     #[test]
-    fn pie_slice_new() {
-        let slice = PieSlice::new("Test", 50.0, Color::Red);
-        assert_eq!(slice.label(), "Test");
-        assert_eq!(slice.value(), 50.0);
-        assert_eq!(slice.color(), Color::Red);
+    fn piechart_pie_geometry_fit_fills_area() {
+        for (width, height) in [(60u16, 20u16), (20, 30), (7, 3), (1, 1), (0, 0)] {
+            let radius = (width / 2).min(height / 2 * 2).saturating_sub(1);
+            assert_eq!(
+                PieChart::default().pie_geometry(Rect::new(0, 0, width, height)),
+                (width / 2, height / 2, radius),
+                "{width}x{height}"
+            );
+        }
     }
 
     #[test]
-    fn piechart_new() {
-        let slices = vec![
-            PieSlice::new("A", 30.0, Color::Red),
-            PieSlice::new("B", 70.0, Color::Blue),
+    fn piechart_radius_fixed_and_max() {
+        let area = Rect::new(0, 0, 60, 20);
+        let radius = |radius| PieChart::default().radius(radius).pie_geometry(area).2;
+        assert_eq!(radius(PieRadius::Fit), 19);
+        assert_eq!(radius(PieRadius::Fixed(8)), 8);
+        assert_eq!(radius(PieRadius::Fixed(40)), 40);
+        assert_eq!(radius(PieRadius::Max(8)), 8);
+        assert_eq!(radius(PieRadius::Max(40)), 19);
+    }
+
+    #[test]
+    fn piechart_pie_alignment() {
+        let area = Rect::new(0, 0, 60, 20);
+        let chart = |horizontal, vertical| {
+            PieChart::default()
+                .radius(PieRadius::Fixed(4))
+                .pie_alignment(horizontal, vertical)
+        };
+        let expected = [
+            (HorizontalAlignment::Left, VerticalAlignment::Top, (4, 2)),
+            (
+                HorizontalAlignment::Center,
+                VerticalAlignment::Center,
+                (30, 10),
+            ),
+            (
+                HorizontalAlignment::Right,
+                VerticalAlignment::Bottom,
+                (55, 17),
+            ),
         ];
-        let piechart = PieChart::new(slices.clone());
-        assert_eq!(piechart.slices, slices);
+        for (horizontal, vertical, (x, y)) in expected {
+            assert_eq!(chart(horizontal, vertical).pie_geometry(area), (x, y, 4));
+        }
+        // An oversized pie keeps its center on the area
+        let chart = chart(HorizontalAlignment::Right, VerticalAlignment::Bottom);
+        assert_eq!(
+            chart.radius(PieRadius::Fixed(80)).pie_geometry(area),
+            (0, 0, 80)
+        );
     }
 
     #[test]
-    fn piechart_default() {
-        let piechart = PieChart::default();
-        assert!(piechart.slices.is_empty());
-        assert!(piechart.show_legend);
-        assert!(piechart.show_percentages);
+    fn piechart_fixed_radius_renders_same_size() {
+        // The bounding box of the cells the pie was drawn in
+        let pie_bounds = |chart: PieChart, width, height| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, width, height));
+            Widget::render(&chart, buffer.area, &mut buffer);
+            let drawn: Vec<_> = buffer
+                .area
+                .positions()
+                .filter(|&pos| buffer[pos].symbol() != " ")
+                .collect();
+            let left = drawn.iter().map(|pos| pos.x).min().unwrap();
+            let right = drawn.iter().map(|pos| pos.x).max().unwrap();
+            let top = drawn.iter().map(|pos| pos.y).min().unwrap();
+            let bottom = drawn.iter().map(|pos| pos.y).max().unwrap();
+            Rect::new(left, top, right - left + 1, bottom - top + 1)
+        };
+        for resolution in Resolution::iter() {
+            let chart = PieChart::new(vec![PieSlice::auto("A", 1.0), PieSlice::auto("B", 2.0)])
+                .show_legend(false)
+                .resolution(resolution)
+                .radius(PieRadius::Fixed(6));
+            let small = pie_bounds(chart.clone(), 30, 16);
+            let large = pie_bounds(chart.clone(), 60, 30);
+            assert_eq!(small.as_size(), large.as_size(), "{resolution}");
+
+            let top_left = chart
+                .clone()
+                .pie_alignment(HorizontalAlignment::Left, VerticalAlignment::Top);
+            let corner = pie_bounds(top_left, 60, 30);
+            assert_eq!((corner.x, corner.y), (0, 0), "{resolution}");
+            assert_eq!(corner.as_size(), large.as_size(), "{resolution}");
+
+            let bottom_right =
+                chart.pie_alignment(HorizontalAlignment::Right, VerticalAlignment::Bottom);
+            let corner = pie_bounds(bottom_right, 60, 30);
+            assert_eq!((corner.right(), corner.bottom()), (60, 30), "{resolution}");
+        }
     }
 
     #[test]
-    fn piechart_slices() {
-        let slices = vec![PieSlice::new("Test", 100.0, Color::Green)];
-        let piechart = PieChart::default().slices(slices.clone());
-        assert_eq!(piechart.slices, slices);
+    fn piechart_oversized_radius_fills_area() {
+        for resolution in Resolution::iter() {
+            for radius in [30_000, u16::MAX] {
+                let chart = PieChart::new(vec![PieSlice::auto("A", 1.0)])
+                    .show_legend(false)
+                    .resolution(resolution)
+                    .radius(PieRadius::Fixed(radius));
+                let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 10));
+                Widget::render(&chart, buffer.area, &mut buffer);
+                let blank = buffer.content.iter().filter(|cell| cell.symbol() == " ");
+                assert_eq!(blank.count(), 0, "{resolution} {radius}");
+
+                // The outline of the placeholder ring lies outside the area
+                let chart = PieChart::default()
+                    .resolution(resolution)
+                    .radius(PieRadius::Fixed(radius))
+                    .placeholder(Placeholder::new("").ring(Style::new()));
+                let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 10));
+                Widget::render(&chart, buffer.area, &mut buffer);
+                assert_eq!(buffer, Buffer::empty(buffer.area), "{resolution} {radius}");
+            }
+        }
     }
 
+    // --- Label truncation ---
+
     #[test]
-    fn piechart_style() {
-        let style = Style::default().fg(Color::Red);
-        let piechart = PieChart::default().style(style);
-        assert_eq!(piechart.style, style);
+    fn piechart_fit_legend_text_keeps_percentage() {
+        let chart = PieChart::new(vec![
+            PieSlice::auto("Programming languages", 75.0),
+            PieSlice::auto("Go", 25.0),
+        ]);
+        assert_eq!(
+            chart.fit_legend_text(0, 100.0, 40),
+            "■ Programming languages 75.0%"
+        );
+        assert_eq!(chart.fit_legend_text(0, 100.0, 16), "■ Program… 75.0%");
+        assert_eq!(chart.fit_legend_text(1, 100.0, 16), "■ Go 25.0%");
+
+        let chart = chart.legend_truncation(LegendTruncation::Middle);
+        assert_eq!(chart.fit_legend_text(0, 100.0, 16), "■ Prog…ges 75.0%");
+
+        let chart = chart.legend_truncation(LegendTruncation::Hide);
+        assert_eq!(chart.fit_legend_text(0, 100.0, 16), "■ 75.0%");
+        assert_eq!(chart.fit_legend_text(1, 100.0, 16), "■ Go 25.0%");
     }
 
     #[test]
-    fn piechart_show_legend() {
-        let piechart = PieChart::default().show_legend(false);
-        assert!(!piechart.show_legend);
+    fn piechart_narrow_legend_truncates_labels() {
+        let chart = PieChart::new(vec![
+            PieSlice::auto("Programming languages", 75.0),
+            PieSlice::auto("Go", 25.0),
+        ]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 60, 20));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        let row = |y| {
+            (40..60)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+        };
+        assert_eq!(row(1), "■ Programming… 75.0%");
+        assert_eq!(row(3), "■ Go 25.0%          ");
+
+        let chart = chart
+            .legend_layout(LegendLayout::Horizontal)
+            .legend_position(LegendPosition::Bottom);
+        let text = render_to_string(&chart, 60, 20, None);
+        assert!(text.contains("■ Programming languages 75.0%  ■ Go 25.0%"));
     }
 
     #[test]
-    fn piechart_show_percentages() {
-        let piechart = PieChart::default().show_percentages(false);
-        assert!(!piechart.show_percentages);
+    fn piechart_table_legend_narrows_label_column() {
+        let chart = PieChart::new(vec![
+            PieSlice::auto("Programming languages", 75.0),
+            PieSlice::auto("Go", 25.0),
+        ])
+        .legend_layout(LegendLayout::Table)
+        .legend_config(LegendConfig::new().vertical_min_width(0));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 60, 20));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        let row = |y| {
+            (40..60)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+        };
+        assert_eq!(row(1), "■ Progra…  75  75.0%");
+        assert_eq!(row(2), "■ Go       25  25.0%");
     }
 
+    // --- Automatic legend position ---
+
     #[test]
-    fn piechart_pie_char() {
-        let piechart = PieChart::default().pie_char('█');
-        assert_eq!(piechart.pie_char, '█');
+    fn piechart_auto_legend_follows_aspect_ratio() {
+        let chart = PieChart::new(vec![
+            PieSlice::auto("Rust", 60.0),
+            PieSlice::auto("Go", 40.0),
+        ]);
+        let right = chart.clone().legend_position(LegendPosition::Right);
+        let bottom = chart.clone().legend_position(LegendPosition::Bottom);
+        let chart = chart.legend_position(LegendPosition::Auto);
+        let wide = Rect::new(0, 0, 60, 20);
+        let fitted = chart.fit_legend(wide, &chart.entries());
+        assert_eq!(fitted.options.legend_position, LegendPosition::Right);
+        assert!(fitted.options.show_percentages);
+        assert_eq!(
+            chart.resolve_layout(wide, &chart.entries()).1,
+            right.resolve_layout(wide, &right.entries()).1
+        );
+
+        let tall = Rect::new(0, 0, 30, 30);
+        assert_eq!(
+            chart
+                .fit_legend(tall, &chart.entries())
+                .options
+                .legend_position,
+            LegendPosition::Bottom
+        );
+        assert_eq!(
+            chart.resolve_layout(tall, &chart.entries()).1,
+            bottom.resolve_layout(tall, &bottom.entries()).1
+        );
     }
 
     #[test]
-    fn piechart_total_value() {
-        let slices = vec![
-            PieSlice::new("A", 30.0, Color::Red),
-            PieSlice::new("B", 70.0, Color::Blue),
-        ];
-        let piechart = PieChart::new(slices);
-        assert_eq!(piechart.total_value(), 100.0);
+    fn piechart_auto_legend_uses_other_side() {
+        // A wide but short area has no room for the entries on the right
+        let chart = (0..6)
+            .map(|i| PieSlice::auto("Item", f64::from(i + 1)))
+            .collect::<PieChart>()
+            .legend_position(LegendPosition::Auto);
+        let fitted = chart.fit_legend(Rect::new(0, 0, 60, 12), &chart.entries());
+        assert_eq!(fitted.options.legend_position, LegendPosition::Bottom);
+        assert!(fitted.options.show_percentages);
     }
 
     #[test]
-    fn piechart_percentage() {
-        let slices = vec![
-            PieSlice::new("A", 30.0, Color::Red),
-            PieSlice::new("B", 70.0, Color::Blue),
-        ];
-        let piechart = PieChart::new(slices);
+    fn piechart_auto_legend_abbreviates_then_hides() {
+        let chart = PieChart::new(vec![
+            PieSlice::auto("Rust", 60.0),
+            PieSlice::auto("Go", 40.0),
+        ])
+        .legend_position(LegendPosition::Auto)
+        .legend_bars(4);
+        let fitted = chart.fit_legend(Rect::new(0, 0, 28, 10), &chart.entries());
+        assert!(fitted.options.show_legend);
+        assert!(!fitted.options.show_percentages);
+        assert_eq!(fitted.options.legend_bar_width, 0);
+        assert!(!render_to_string(&chart, 28, 10, None).contains('%'));
+        assert!(render_to_string(&chart, 28, 10, None).contains("Rust"));
+
+        let fitted = chart.fit_legend(Rect::new(0, 0, 14, 6), &chart.entries());
+        assert!(!fitted.options.show_legend);
         assert_eq!(
-            piechart.percentage(&PieSlice::new("A", 30.0, Color::Red)),
-            30.0
+            chart
+                .resolve_layout(Rect::new(0, 0, 14, 6), &chart.entries())
+                .1,
+            (Rect::new(0, 0, 14, 6), None)
         );
+        assert!(!render_to_string(&chart, 14, 6, None).contains("Rust"));
     }
 
-    // Render tests - using macros for common patterns
-    render_empty_test!(piechart_render_empty_area, PieChart::default());
-
-    render_with_size_test!(
-        piechart_render_with_block,
-        {
-            let slices = vec![PieSlice::new("Test", 100.0, Color::Red)];
-            PieChart::new(slices).block(Block::bordered())
-        },
-        width: 20,
-        height: 10
-    );
+    #[test]
+    fn piechart_auto_legend_borrows_block_and_title() {
+        let chart = PieChart::new(vec![PieSlice::auto("Rust", 60.0)])
+            .block(Block::bordered().title("Languages"))
+            .legend_title("Share")
+            .legend_position(LegendPosition::Auto);
+        let (fitted, _) = chart.resolve_layout(Rect::new(0, 0, 60, 20), &chart.entries());
+        assert!(matches!(
+            fitted.options.block,
+            Some(MaybeBorrowed::Borrowed(_))
+        ));
+        assert!(matches!(
+            fitted.options.legend_title,
+            Some(MaybeBorrowed::Borrowed(_))
+        ));
+        assert_eq!(fitted.options.block, chart.options.block);
+    }
 
-    render_test!(
-        piechart_render_basic,
-        {
-            let slices = vec![
-                PieSlice::new("Rust", 45.0, Color::Red),
-                PieSlice::new("Go", 30.0, Color::Blue),
-                PieSlice::new("Python", 25.0, Color::Green),
-            ];
-            PieChart::new(slices)
-        },
-        Rect::new(0, 0, 40, 20)
-    );
+    // --- Legend bars ---
 
     #[test]
-    fn piechart_styled_trait() {
-        use ratatui::style::Stylize;
-        let piechart = PieChart::default().red();
-        assert_eq!(piechart.style.fg, Some(Color::Red));
+    fn piechart_percent_bar_eighths() {
+        assert_eq!(PieChart::percent_bar(0.0, 4), "    ");
+        assert_eq!(PieChart::percent_bar(100.0, 4), "████");
+        assert_eq!(PieChart::percent_bar(50.0, 4), "██  ");
+        assert_eq!(PieChart::percent_bar(45.0, 10), "████▌     ");
+        assert_eq!(PieChart::percent_bar(1.0, 10), "▏         ");
+        assert_eq!(PieChart::percent_bar(150.0, 2), "██");
+        assert_eq!(PieChart::percent_bar(-5.0, 2), "  ");
     }
 
     #[test]
-    fn piechart_with_multiple_slices() {
-        let slices = vec![
-            PieSlice::new("A", 25.0, Color::Red),
-            PieSlice::new("B", 25.0, Color::Blue),
-            PieSlice::new("C", 25.0, Color::Green),
-            PieSlice::new("D", 25.0, Color::Yellow),
-        ];
-        let piechart = PieChart::new(slices);
-        assert_eq!(piechart.total_value(), 100.0);
+    fn piechart_legend_bars_in_entries() {
+        let chart = PieChart::new(vec![
+            PieSlice::auto("Rust", 75.0),
+            PieSlice::auto("Go", 25.0),
+        ])
+        .legend_bars(4);
+        assert_eq!(chart.format_legend_text(0, 100.0, ""), "■ ███  Rust 75.0%");
+        assert_eq!(
+            chart
+                .clone()
+                .show_percentages(false)
+                .format_legend_text(1, 100.0, ""),
+            "■ █    Go"
+        );
+        assert_eq!(
+            chart.legend_item_width(0, 100.0),
+            PieChart::new(vec![PieSlice::auto("Rust", 75.0)]).legend_item_width(0, 100.0) + 5
+        );
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 60, 20));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        let row = |y| {
+            (40..60)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+        };
+        assert!(row(1).starts_with("■ ███  Rust 75.0%"));
+        assert!(row(3).starts_with("■ █    Go 25.0%"));
     }
 
-    // Using render macro for the visual test
-    render_with_size_test!(
-        piechart_multi_slice_render,
-        {
-            let slices = vec![
-                PieSlice::new("A", 25.0, Color::Red),
-                PieSlice::new("B", 25.0, Color::Blue),
-                PieSlice::new("C", 25.0, Color::Green),
-                PieSlice::new("D", 25.0, Color::Yellow),
-            ];
-            PieChart::new(slices)
-        },
-        width: 50,
-        height: 30
-    );
+    // --- Scrolling legend ---
 
     #[test]
-    fn piechart_zero_values() {
-        let slices = vec![
-            PieSlice::new("A", 0.0, Color::Red),
-            PieSlice::new("B", 0.0, Color::Blue),
-        ];
-        let piechart = PieChart::new(slices);
-        assert_eq!(piechart.total_value(), 0.0);
+    fn piechart_vertical_legend_overflow_indicators() {
+        // 18 legend lines: indicators on the first and last, 8 entries between
+        let chart: PieChart = ["L0", "L1", "L2", "L3", "L4", "L5", "L6", "L7", "L8", "L9"]
+            .map(|label| PieSlice::auto(label, 10.0))
+            .into_iter()
+            .collect();
+        let mut state = PieChartState::new();
+        let text = render_to_string(&chart, 60, 20, Some(&mut state));
+        assert!(text.contains("L7 10.0%"));
+        assert!(!text.contains("L8 10.0%"));
+        assert!(text.contains("▼ 2 more"));
+        assert!(!text.contains("▲"));
+
+        let mut state = PieChartState::new().with_legend_offset(1);
+        let text = render_to_string(&chart, 60, 20, Some(&mut state));
+        assert!(text.contains("▲ 1 more") && text.contains("▼ 1 more"));
+        assert!(!text.contains("L0 10.0%"));
     }
 
     #[test]
-    fn piechart_method_chaining() {
-        use ratatui::widgets::Block;
-
-        let slices = vec![PieSlice::new("Test", 100.0, Color::Red)];
-        let piechart = PieChart::new(slices)
-            .show_legend(true)
-            .show_percentages(true)
-            .pie_char('█')
-            .block(Block::bordered().title("Test"))
-            .style(Style::default().fg(Color::White));
+    fn piechart_vertical_legend_keeps_an_entry_when_short() {
+        let chart: PieChart = ["L0", "L1", "L2", "L3", "L4", "L5", "L6", "L7", "L8", "L9"]
+            .map(|label| PieSlice::auto(label, 10.0))
+            .into_iter()
+            .collect();
+        let legend_text = |height: u16, state: &mut PieChartState| {
+            let area = Rect::new(0, 0, 12, height);
+            let mut buffer = Buffer::empty(area);
+            chart.render_vertical_legend(&mut buffer, area, &chart.entries(), state);
+            buffer
+                .content
+                .iter()
+                .map(ratatui::buffer::Cell::symbol)
+                .collect::<String>()
+        };
 
-        assert!(piechart.show_legend);
-        assert!(piechart.show_percentages);
-        assert_eq!(piechart.pie_char, '█');
-        assert!(piechart.block.is_some());
-        assert_eq!(piechart.style.fg, Some(Color::White));
+        let text = legend_text(2, &mut PieChartState::new().with_selected(Some(4)));
+        assert_eq!(text, "+9 more     ■ L4 10.0%  ");
+        let text = legend_text(1, &mut PieChartState::new());
+        assert_eq!(text, "■ L0 10.0%  ");
     }
 
     #[test]
-    fn piechart_custom_symbols() {
-        use crate::symbols;
-
-        let piechart = PieChart::default().pie_char(symbols::PIE_CHAR_BLOCK);
-        assert_eq!(piechart.pie_char, '█');
-
-        let piechart = PieChart::default().pie_char(symbols::PIE_CHAR_CIRCLE);
-        assert_eq!(piechart.pie_char, '◉');
-
-        let piechart = PieChart::default().pie_char(symbols::PIE_CHAR_SQUARE);
-        assert_eq!(piechart.pie_char, '■');
+    fn piechart_vertical_legend_offset_follows_selection() {
+        let chart: PieChart = ["L0", "L1", "L2", "L3", "L4", "L5", "L6", "L7", "L8", "L9"]
+            .map(|label| PieSlice::auto(label, 10.0))
+            .into_iter()
+            .collect();
+        let mut state = PieChartState::new().with_selected(Some(9));
+        let text = render_to_string(&chart, 60, 20, Some(&mut state));
+        assert_eq!(state.legend_offset(), 2);
+        assert!(text.contains("L9 10.0%") && text.contains("▲ 2 more"));
+        assert!(!text.contains("▼"));
+
+        // An offset past the end is clamped
+        let mut state = PieChartState::new().with_legend_offset(50);
+        render_to_string(&chart, 60, 20, Some(&mut state));
+        assert_eq!(state.legend_offset(), 2);
     }
 
     #[test]
-    fn piechart_is_angle_in_slice() {
-        use std::f64::consts::PI;
+    fn piechart_vertical_legend_fits_without_indicators() {
+        let chart: PieChart = ["L0", "L1", "L2", "L3", "L4", "L5", "L6", "L7", "L8"]
+            .map(|label| PieSlice::auto(label, 10.0))
+            .into_iter()
+            .collect();
+        let mut state = PieChartState::new().with_legend_offset(3);
+        let text = render_to_string(&chart, 60, 20, Some(&mut state));
+        assert!(text.contains("L0 11.1%") && text.contains("L8 11.1%"));
+        assert!(!text.contains("more"));
+        assert_eq!(state.legend_offset(), 0);
+    }
 
-        // Test angle in range
-        assert!(PieChart::is_angle_in_slice(PI / 4.0, 0.0, PI / 2.0));
+    #[test]
+    fn piechart_selected_legend_entry_is_highlighted() {
+        let chart = PieChart::new(vec![
+            PieSlice::auto("Rust", 45.0),
+            PieSlice::auto("Go", 30.0),
+        ])
+        .highlight_style(Modifier::BOLD);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 60, 20));
+        let mut state = PieChartState::new().with_selected(Some(1));
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+        let cell = |label: &str| {
+            buffer
+                .area
+                .positions()
+                .find(|&pos| buffer[pos].symbol() == label)
+                .map(|pos| buffer[pos].modifier)
+                .unwrap()
+        };
+        assert!(cell("G").contains(Modifier::BOLD));
+        assert!(!cell("R").contains(Modifier::BOLD));
+    }
 
-        // Test angle outside range
-        assert!(!PieChart::is_angle_in_slice(PI, 0.0, PI / 2.0));
+    // --- Table legend ---
 
-        // Test wrap around
-        assert!(PieChart::is_angle_in_slice(0.1, 1.5 * PI, 0.5));
+    #[test]
+    fn piechart_table_legend_aligns_columns() {
+        let chart = PieChart::new(vec![
+            PieSlice::auto("Rust", 45.0),
+            PieSlice::auto("Go", 5.0),
+            PieSlice::auto("Python", 50.0),
+        ])
+        .legend_layout(LegendLayout::Table);
+        let expected: [(LegendTable, bool, &[&str]); 3] = [
+            (
+                LegendTable::new(),
+                true,
+                &[
+                    "■ Rust    45  45.0%",
+                    "■ Go       5   5.0%",
+                    "■ Python  50  50.0%",
+                ],
+            ),
+            (
+                LegendTable::new().header(true).footer(true),
+                true,
+                &[
+                    "  Label   Value       %",
+                    "■ Rust       45   45.0%",
+                    "■ Go          5    5.0%",
+                    "■ Python     50   50.0%",
+                    "  Total     100  100.0%",
+                ],
+            ),
+            (
+                LegendTable::new().values(false),
+                false,
+                &["■ Rust  ", "■ Go    ", "■ Python"],
+            ),
+        ];
+        for (table, show_percentages, lines) in expected {
+            let chart = chart
+                .clone()
+                .legend_table(table)
+                .show_percentages(show_percentages);
+            let rows = chart.table_rows(&chart.entries());
+            let widths = PieChart::table_column_widths(&rows);
+            let text: Vec<_> = rows
+                .iter()
+                .map(|(_, cells)| chart.format_table_row(cells, &widths))
+                .collect();
+            assert_eq!(text, lines);
+        }
     }
 
-    // --- Resolution ---
+    #[test]
+    fn piechart_table_legend_aligns_wide_labels() {
+        let chart = PieChart::new(vec![
+            PieSlice::auto("日本語", 40.0),
+            PieSlice::auto("Go", 60.0),
+        ])
+        .legend_layout(LegendLayout::Table);
+        let rows = chart.table_rows(&chart.entries());
+        let widths = PieChart::table_column_widths(&rows);
+        let first = chart.format_table_row(&rows[0].1, &widths);
+        let second = chart.format_table_row(&rows[1].1, &widths);
+        assert_eq!(
+            [&first, &second],
+            ["■ 日本語  40  40.0%", "■ Go      60  60.0%"]
+        );
+        assert_eq!(first.width(), second.width());
+        assert_eq!(chart.table_width(&chart.entries()), 19);
+    }
 
-    matches_test!(
-        piechart_resolution_standard,
-        PieChart::default()
-            .resolution(Resolution::Standard)
-            .resolution,
-        Resolution::Standard
-    );
-    matches_test!(
-        piechart_resolution_braille,
-        PieChart::default()
-            .resolution(Resolution::Braille)
-            .resolution,
-        Resolution::Braille
-    );
-    matches_test!(
-        piechart_high_resolution_true,
-        PieChart::default().high_resolution(true).resolution,
-        Resolution::Braille
-    );
-    matches_test!(
-        piechart_high_resolution_false,
-        PieChart::default().high_resolution(false).resolution,
-        Resolution::Standard
-    );
+    #[test]
+    fn piechart_table_legend_width() {
+        let chart = PieChart::new(vec![
+            PieSlice::auto("Rust", 45.0),
+            PieSlice::auto("Go", 5.0),
+            PieSlice::auto("Python", 50.0),
+        ])
+        .legend_layout(LegendLayout::Table)
+        .legend_table(LegendTable::new().header(true).footer(true));
+        assert_eq!(chart.calculate_legend_width(&chart.entries()), 23 + 2);
+    }
 
-    // --- Legend position / layout / alignment setters ---
+    #[test]
+    fn piechart_table_legend_renders_rows() {
+        let chart = PieChart::new(vec![
+            PieSlice::auto("Rust", 45.0),
+            PieSlice::auto("Go", 5.0),
+            PieSlice::auto("Python", 50.0),
+        ])
+        .legend_layout(LegendLayout::Table)
+        .legend_table(LegendTable::new().footer(true))
+        .legend_position(LegendPosition::Bottom);
+        let text = render_to_string(&chart, 60, 20, None);
+        assert!(text.contains("■ Go        5    5.0%"));
+        assert!(text.contains("  Total   100  100.0%"));
+    }
 
-    matches_test!(
-        piechart_legend_position_left,
-        PieChart::default()
-            .legend_position(LegendPosition::Left)
-            .legend_position,
-        LegendPosition::Left
-    );
-    matches_test!(
-        piechart_legend_position_right,
-        PieChart::default()
-            .legend_position(LegendPosition::Right)
-            .legend_position,
-        LegendPosition::Right
-    );
-    matches_test!(
-        piechart_legend_position_top,
-        PieChart::default()
-            .legend_position(LegendPosition::Top)
-            .legend_position,
-        LegendPosition::Top
-    );
-    matches_test!(
-        piechart_legend_position_bottom,
-        PieChart::default()
-            .legend_position(LegendPosition::Bottom)
-            .legend_position,
-        LegendPosition::Bottom
-    );
-    matches_test!(
-        piechart_legend_layout_horizontal,
-        PieChart::default()
-            .legend_layout(LegendLayout::Horizontal)
-            .legend_layout,
-        LegendLayout::Horizontal
-    );
-    matches_test!(
-        piechart_legend_layout_vertical,
-        PieChart::default()
-            .legend_layout(LegendLayout::Vertical)
-            .legend_layout,
-        LegendLayout::Vertical
-    );
-    matches_test!(
-        piechart_legend_alignment_left,
-        PieChart::default()
-            .legend_alignment(LegendAlignment::Left)
-            .legend_alignment,
-        LegendAlignment::Left
-    );
-    matches_test!(
-        piechart_legend_alignment_center,
-        PieChart::default()
-            .legend_alignment(LegendAlignment::Center)
-            .legend_alignment,
-        LegendAlignment::Center
-    );
-    matches_test!(
-        piechart_legend_alignment_right,
-        PieChart::default()
-            .legend_alignment(LegendAlignment::Right)
-            .legend_alignment,
-        LegendAlignment::Right
-    );
+    #[test]
+    fn piechart_negative_values_do_not_cancel_out() {
+        let chart = PieChart::new(vec![PieSlice::auto("A", 50.0), PieSlice::auto("B", -50.0)]);
+        assert_eq!(chart.entries().total, 50.0);
+        assert_eq!(chart.percentage(0, chart.entries().total), 100.0);
+    }
 
     // --- legend_marker setter ---
 
@@ -1497,7 +5501,7 @@ mod tests {
     fn piechart_legend_marker_custom() {
         use crate::symbols::LEGEND_MARKER;
         let piechart = PieChart::default().legend_marker(LEGEND_MARKER);
-        assert_eq!(piechart.legend_marker, LEGEND_MARKER);
+        assert_eq!(piechart.options.legend_marker, LEGEND_MARKER);
     }
 
     // --- format_legend_text ---
@@ -1509,7 +5513,7 @@ mod tests {
             PieSlice::new("Go", 50.0, Color::Blue),
         ];
         let piechart = PieChart::new(slices.clone()).show_percentages(true);
-        let text = piechart.format_legend_text(&slices[0], 100.0, "");
+        let text = piechart.format_legend_text(0, 100.0, "");
         assert!(text.contains("Rust"));
         assert!(text.contains("50.0%"));
     }
@@ -1518,7 +5522,7 @@ mod tests {
     fn piechart_format_legend_text_without_percentage() {
         let slices = vec![PieSlice::new("Rust", 50.0, Color::Red)];
         let piechart = PieChart::new(slices.clone()).show_percentages(false);
-        let text = piechart.format_legend_text(&slices[0], 100.0, "");
+        let text = piechart.format_legend_text(0, 100.0, "");
         assert!(text.contains("Rust"));
         assert!(!text.contains('%'));
     }
@@ -1527,7 +5531,7 @@ mod tests {
     fn piechart_format_legend_text_zero_total() {
         let slices = vec![PieSlice::new("X", 0.0, Color::Red)];
         let piechart = PieChart::new(slices.clone()).show_percentages(true);
-        let text = piechart.format_legend_text(&slices[0], 0.0, "");
+        let text = piechart.format_legend_text(0, 0.0, "");
         assert!(text.contains("0.0%"));
     }
 
@@ -1755,7 +5759,7 @@ mod tests {
         Rect::new(0, 0, 60, 20)
     );
 
-    // --- calculate_layout: small area returns no legend ---
+    // --- resolve_layout: small area returns no legend ---
 
     #[test]
     fn piechart_layout_too_small_no_legend() {
@@ -1763,7 +5767,7 @@ mod tests {
         let piechart = PieChart::new(slices).show_legend(true);
         // area too narrow (< 20)
         let area = Rect::new(0, 0, 10, 5);
-        let (pie_area, legend_opt) = piechart.calculate_layout(area);
+        let (pie_area, legend_opt) = piechart.resolve_layout(area, &piechart.entries()).1;
         assert_eq!(pie_area, area);
         assert!(legend_opt.is_none());
     }
@@ -1773,7 +5777,7 @@ mod tests {
         let slices = vec![PieSlice::new("A", 100.0, Color::Red)];
         let piechart = PieChart::new(slices).show_legend(false);
         let area = Rect::new(0, 0, 60, 20);
-        let (pie_area, legend_opt) = piechart.calculate_layout(area);
+        let (pie_area, legend_opt) = piechart.resolve_layout(area, &piechart.entries()).1;
         assert_eq!(pie_area, area);
         assert!(legend_opt.is_none());
     }
@@ -1796,7 +5800,7 @@ mod tests {
     fn piechart_percentage_zero_total() {
         let slices = vec![PieSlice::new("A", 0.0, Color::Red)];
         let piechart = PieChart::new(slices.clone());
-        assert_eq!(piechart.percentage(&slices[0]), 0.0);
+        assert_eq!(piechart.percentage(0, piechart.entries().total), 0.0);
     }
 
     // --- calculate_legend_width Vertical: zero total with percentages (line 1041) ---
@@ -1812,7 +5816,7 @@ mod tests {
         let piechart = PieChart::new(slices)
             .legend_layout(LegendLayout::Vertical)
             .show_percentages(true);
-        let width = piechart.calculate_legend_width();
+        let width = piechart.calculate_legend_width(&piechart.entries());
         // Each label shows "• A 0.0%  " — width must be > 0
         assert!(width > 0);
     }
@@ -1924,7 +5928,8 @@ mod tests {
     fn piechart_layout_horizontal_split_too_narrow() {
         let area = Rect::new(0, 0, 5, 20);
         // legend_width (100) > area.width (5) → early return
-        let (pie_area, legend_opt) = PieChart::<'_>::layout_horizontal_split(area, 100, true);
+        let (pie_area, legend_opt) =
+            PieChart::<'_>::layout_horizontal_split(area, 100, true, &LegendConfig::new());
         assert_eq!(pie_area, area);
         assert!(legend_opt.is_none());
     }
@@ -1935,7 +5940,8 @@ mod tests {
     fn piechart_layout_vertical_split_too_short() {
         let area = Rect::new(0, 0, 60, 3);
         // legend_height (100) > area.height (3) → early return
-        let (pie_area, legend_opt) = PieChart::<'_>::layout_vertical_split(area, 100, true);
+        let (pie_area, legend_opt) =
+            PieChart::<'_>::layout_vertical_split(area, 100, true, &LegendConfig::new());
         assert_eq!(pie_area, area);
         assert!(legend_opt.is_none());
     }
@@ -1952,7 +5958,7 @@ mod tests {
         let piechart = PieChart::new(slices)
             .legend_layout(LegendLayout::Horizontal)
             .show_percentages(true);
-        let width = piechart.calculate_legend_width();
+        let width = piechart.calculate_legend_width(&piechart.entries());
         assert!(width > 0);
     }
 
@@ -1965,7 +5971,7 @@ mod tests {
         let piechart = PieChart::new(slices)
             .legend_layout(LegendLayout::Horizontal)
             .show_percentages(false);
-        let width = piechart.calculate_legend_width();
+        let width = piechart.calculate_legend_width(&piechart.entries());
         assert!(width > 0);
     }
 
@@ -1978,7 +5984,7 @@ mod tests {
         let piechart = PieChart::new(slices)
             .legend_layout(LegendLayout::Horizontal)
             .show_percentages(true);
-        let width = piechart.calculate_legend_width();
+        let width = piechart.calculate_legend_width(&piechart.entries());
         // Zero total → 0.0% for each item, but width is still > 0
         assert!(width > 0);
     }
@@ -1992,7 +5998,7 @@ mod tests {
             PieSlice::new("Y", 0.0, Color::Blue),
         ];
         let piechart = PieChart::new(slices).show_percentages(true);
-        let width = piechart.calculate_legend_horizontal_width();
+        let width = piechart.calculate_legend_horizontal_width(&piechart.entries());
         assert!(width > 0);
     }
 
@@ -2000,7 +6006,7 @@ mod tests {
     fn piechart_calculate_legend_horizontal_width_without_percentages() {
         let slices = vec![PieSlice::new("Item", 100.0, Color::Green)];
         let piechart = PieChart::new(slices).show_percentages(false);
-        let width = piechart.calculate_legend_horizontal_width();
+        let width = piechart.calculate_legend_horizontal_width(&piechart.entries());
         assert!(width > 0);
     }
 
@@ -2014,7 +6020,7 @@ mod tests {
         ];
         let piechart = PieChart::new(slices);
         // width=1 → columns clamped to 1 → all items stack in one column
-        let height = piechart.calculate_vertical_grid_height(1);
+        let height = piechart.calculate_vertical_grid_height(1, &piechart.entries());
         assert!(height >= 4);
     }
 
@@ -2053,23 +6059,49 @@ mod tests {
     fn piechart_legend_item_width_matches_text() {
         let slices = vec![PieSlice::new("Rust", 50.0, Color::Red)];
         let chart = PieChart::new(slices.clone()).show_percentages(true);
-        let expected = chart
-            .format_legend_text(&slices[0], 100.0, "  ")
-            .chars()
-            .count();
-        assert_eq!(
-            usize::from(chart.legend_item_width(&slices[0], 100.0)),
-            expected
-        );
+        let expected = chart.format_legend_text(0, 100.0, "  ").width();
+        assert_eq!(usize::from(chart.legend_item_width(0, 100.0)), expected);
     }
 
     #[test]
     fn piechart_legend_item_width_unicode_label() {
-        // Multi-byte labels must be measured by character count, not byte length.
+        // Labels are measured in terminal cells, not bytes or characters.
         let slices = vec![PieSlice::new("日本語", 100.0, Color::Red)];
         let chart = PieChart::new(slices.clone()).show_percentages(false);
-        // "■ 日本語  " => marker(1) + space(1) + 3 chars + 2 trailing spaces = 7
-        assert_eq!(chart.legend_item_width(&slices[0], 100.0), 7);
+        // "■ 日本語  " => marker(1) + space(1) + 3 wide chars(6) + 2 trailing spaces = 10
+        assert_eq!(chart.legend_item_width(0, 100.0), 10);
+    }
+
+    #[test]
+    fn piechart_text_width_counts_cells() {
+        assert_eq!(PieChart::text_width("Rust"), 4);
+        assert_eq!(PieChart::text_width("中文"), 4);
+        assert_eq!(PieChart::text_width("🦀"), 2);
+        assert_eq!(PieChart::text_width("e\u{301}"), 1);
+        assert_eq!(PieChart::text_width("𝐁𝐨𝐥𝐝"), 4);
+    }
+
+    #[test]
+    fn piechart_right_aligned_wide_label() {
+        let chart = PieChart::new(vec![
+            PieSlice::auto("中文", 50.0),
+            PieSlice::auto("Go", 50.0),
+        ])
+        .legend_alignment(LegendAlignment::Right);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 60, 20));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        // Both entries end in the last column of the legend
+        let last = |y| {
+            (0..60)
+                .rev()
+                .find(|&x| buffer[(x, y)].symbol() != " ")
+                .unwrap()
+        };
+        let rows: Vec<u16> = (0..20)
+            .filter(|&y| (0..60).any(|x| buffer[(x, y)].symbol() == "%"))
+            .collect();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(last(rows[0]), last(rows[1]));
     }
 
     // --- Full circle rendering (regression for issue #2) ---
@@ -6,6 +6,7 @@
 //! # Module Organization
 //!
 //! - **`test`** - Testing utilities and assertion macros
+//! - **`option`** - Crate-internal `Display`/`FromStr` support for option enums
 //!
 //! # Usage
 //!
@@ -92,5 +93,6 @@
 //! }
 //! ```
 
+pub(crate) mod option;
 #[macro_use]
 pub mod test;
@@ -0,0 +1,96 @@
+//! Macros for option enums.
+//!
+//! [`option_enum!`] gives a fieldless enum a canonical kebab-case name per
+//! variant, an `ALL` list and `iter()`, and `Display`/`FromStr`
+//! implementations built on those names. The names match the `serde`
+//! representation, so CLI flags and config files spell options the same way.
+
+/// Implements `ALL`, `iter()`, `name()`, `Display` and `FromStr` for an enum.
+///
+/// Parsing ignores ASCII case and treats `-`, `_` and spaces as equivalent
+/// separators, so `"rounded-dashed"`, `"Rounded_Dashed"` and
+/// `"RoundedDashed"` all parse.
+///
+/// ```ignore
+/// option_enum! {
+///     Resolution {
+///         Standard => "standard",
+///         Braille => "braille",
+///     }
+/// }
+/// ```
+macro_rules! option_enum {
+    ($type:ident { $($variant:ident => $name:literal),+ $(,)? }) => {
+        impl $type {
+            #[doc = concat!("Every `", stringify!($type), "` variant, in declaration order.")]
+            pub const ALL: &'static [Self] = &[$(Self::$variant),+];
+
+            const NAMES: &'static [&'static str] = &[$($name),+];
+
+            #[doc = concat!("Returns an iterator over every `", stringify!($type), "` variant.")]
+            pub fn iter() -> impl Iterator<Item = Self> {
+                Self::ALL.iter().copied()
+            }
+
+            /// Returns the canonical kebab-case name of this variant.
+            #[must_use]
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name),+
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $type {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.pad(self.name())
+            }
+        }
+
+        impl ::std::str::FromStr for $type {
+            type Err = $crate::error::ParseOptionError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::iter()
+                    .find(|v| $crate::macros::option::name_matches(s, v.name()))
+                    .ok_or_else(|| {
+                        $crate::error::ParseOptionError::new(stringify!($type), s, Self::NAMES)
+                    })
+            }
+        }
+    };
+}
+
+pub(crate) use option_enum;
+
+/// Returns `true` if `input` spells the kebab-case `name`, ignoring ASCII
+/// case and separator style.
+pub(crate) fn name_matches(input: &str, name: &str) -> bool {
+    let is_separator = |c: &char| matches!(c, '-' | '_' | ' ');
+    let mut input = input.trim().chars().filter(|c| !is_separator(c));
+    let mut name = name.chars().filter(|c| !is_separator(c));
+    loop {
+        match (input.next(), name.next()) {
+            (None, None) => return true,
+            (Some(a), Some(b)) if a.eq_ignore_ascii_case(&b) => {}
+            _ => return false,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unnameable_test_items)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn name_matches_ignores_case_and_separators() {
+        assert!(name_matches("rounded-dashed", "rounded-dashed"));
+        assert!(name_matches("ROUNDED_DASHED", "rounded-dashed"));
+        assert!(name_matches("RoundedDashed", "rounded-dashed"));
+        assert!(name_matches(" rounded dashed ", "rounded-dashed"));
+        assert!(!name_matches("rounded", "rounded-dashed"));
+        assert!(!name_matches("rounded-dashed-x", "rounded-dashed"));
+        assert!(!name_matches("", "rounded"));
+    }
+}
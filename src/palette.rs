@@ -0,0 +1,234 @@
+//! Color palettes for automatic slice coloring.
+//!
+//! Slices created with [`PieSlice::auto`](crate::PieSlice::auto) carry no color
+//! of their own. When a chart is rendered, such slices take their color from
+//! the chart's [`Palette`], indexed by the slice's position in the chart.
+//!
+//! # Examples
+//!
+//! ```
+//! use tui_piechart::{PieChart, PieSlice, Palette};
+//!
+//! let slices = vec![
+//!     PieSlice::auto("Rust", 45.0),
+//!     PieSlice::auto("Go", 30.0),
+//!     PieSlice::auto("Python", 25.0),
+//! ];
+//!
+//! // Use a colorblind-safe palette for all uncolored slices
+//! let chart = PieChart::new(slices).palette(Palette::OkabeIto);
+//! ```
+//!
+//! # Cycling
+//!
+//! When a chart has more slices than its palette has colors, the palette
+//! wraps around: slice `i` always receives color `i % len`. The mapping only
+//! depends on the slice index, so the same data always renders the same way.
+
+use ratatui::style::Color;
+
+/// Named terminal colors that render on every color-capable terminal.
+///
+/// Works with 16-color terminals and follows the user's terminal theme.
+pub const CATEGORICAL: [Color; 12] = [
+    Color::Red,
+    Color::Blue,
+    Color::Green,
+    Color::Yellow,
+    Color::Magenta,
+    Color::Cyan,
+    Color::LightRed,
+    Color::LightBlue,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightMagenta,
+    Color::LightCyan,
+];
+
+/// The ten colors of the Tableau 10 palette.
+///
+/// Muted, well-balanced colors commonly used in dashboards. Requires a
+/// terminal with true color support.
+pub const TABLEAU: [Color; 10] = [
+    Color::Rgb(0x4E, 0x79, 0xA7),
+    Color::Rgb(0xF2, 0x8E, 0x2B),
+    Color::Rgb(0xE1, 0x57, 0x59),
+    Color::Rgb(0x76, 0xB7, 0xB2),
+    Color::Rgb(0x59, 0xA1, 0x4F),
+    Color::Rgb(0xED, 0xC9, 0x48),
+    Color::Rgb(0xB0, 0x7A, 0xA1),
+    Color::Rgb(0xFF, 0x9D, 0xA7),
+    Color::Rgb(0x9C, 0x75, 0x5F),
+    Color::Rgb(0xBA, 0xB0, 0xAC),
+];
+
+/// Soft pastel colors (`ColorBrewer` Pastel1).
+///
+/// Best suited for dark terminal backgrounds. Requires a terminal with true
+/// color support.
+pub const PASTEL: [Color; 8] = [
+    Color::Rgb(0xFB, 0xB4, 0xAE),
+    Color::Rgb(0xB3, 0xCD, 0xE3),
+    Color::Rgb(0xCC, 0xEB, 0xC5),
+    Color::Rgb(0xDE, 0xCB, 0xE4),
+    Color::Rgb(0xFE, 0xD9, 0xA6),
+    Color::Rgb(0xFF, 0xFF, 0xCC),
+    Color::Rgb(0xE5, 0xD8, 0xBD),
+    Color::Rgb(0xFD, 0xDA, 0xEC),
+];
+
+/// The Okabe–Ito colorblind-safe palette.
+///
+/// These eight colors remain distinguishable for the most common forms of
+/// color vision deficiency. Black is listed last so that charts with up to
+/// seven slices stay visible on dark backgrounds.
+pub const OKABE_ITO: [Color; 8] = [
+    Color::Rgb(0xE6, 0x9F, 0x00),
+    Color::Rgb(0x56, 0xB4, 0xE9),
+    Color::Rgb(0x00, 0x9E, 0x73),
+    Color::Rgb(0xF0, 0xE4, 0x42),
+    Color::Rgb(0x00, 0x72, 0xB2),
+    Color::Rgb(0xD5, 0x5E, 0x00),
+    Color::Rgb(0xCC, 0x79, 0xA7),
+    Color::Rgb(0x00, 0x00, 0x00),
+];
+
+/// A set of colors used to color slices that have no explicit color.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::style::Color;
+/// use tui_piechart::Palette;
+///
+/// assert_eq!(Palette::Categorical.color(0), Color::Red);
+///
+/// // Indices past the end of the palette wrap around
+/// let len = Palette::Tableau.len();
+/// assert_eq!(Palette::Tableau.color(len), Palette::Tableau.color(0));
+///
+/// // Bring your own colors
+/// const BRAND: [Color; 2] = [Color::Rgb(255, 87, 34), Color::Rgb(33, 150, 243)];
+/// let palette = Palette::Custom(&BRAND);
+/// assert_eq!(palette.color(3), BRAND[1]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Palette<'a> {
+    /// Named terminal colors (default). See [`CATEGORICAL`].
+    #[default]
+    Categorical,
+    /// Tableau 10 colors. See [`TABLEAU`].
+    Tableau,
+    /// Soft pastel colors. See [`PASTEL`].
+    Pastel,
+    /// Okabe–Ito colorblind-safe colors. See [`OKABE_ITO`].
+    OkabeIto,
+    /// A user-provided list of colors.
+    ///
+    /// An empty list yields [`Color::Reset`] for every slice.
+    Custom(&'a [Color]),
+}
+
+impl Palette<'_> {
+    /// Returns the colors of this palette.
+    #[must_use]
+    pub const fn colors(&self) -> &[Color] {
+        match self {
+            Self::Categorical => &CATEGORICAL,
+            Self::Tableau => &TABLEAU,
+            Self::Pastel => &PASTEL,
+            Self::OkabeIto => &OKABE_ITO,
+            Self::Custom(colors) => colors,
+        }
+    }
+
+    /// Returns the number of colors in this palette.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.colors().len()
+    }
+
+    /// Returns `true` if this palette has no colors.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.colors().is_empty()
+    }
+
+    /// Returns the color for the slice at `index`.
+    ///
+    /// Indices wrap around when they exceed the palette length, so any index
+    /// maps to a color deterministically.
+    #[must_use]
+    pub fn color(&self, index: usize) -> Color {
+        let colors = self.colors();
+        if colors.is_empty() {
+            Color::Reset
+        } else {
+            colors[index % colors.len()]
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unnameable_test_items)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn palette_default_is_categorical() {
+        assert_eq!(Palette::default(), Palette::Categorical);
+    }
+
+    #[test]
+    fn palette_lengths() {
+        assert_eq!(Palette::Categorical.len(), 12);
+        assert_eq!(Palette::Tableau.len(), 10);
+        assert_eq!(Palette::Pastel.len(), 8);
+        assert_eq!(Palette::OkabeIto.len(), 8);
+    }
+
+    #[test]
+    fn palette_color_wraps_around() {
+        for palette in [
+            Palette::Categorical,
+            Palette::Tableau,
+            Palette::Pastel,
+            Palette::OkabeIto,
+        ] {
+            let len = palette.len();
+            assert_eq!(palette.color(len), palette.color(0));
+            assert_eq!(palette.color(len * 3 + 2), palette.color(2));
+        }
+    }
+
+    #[test]
+    fn palette_colors_are_distinct() {
+        for palette in [
+            Palette::Categorical,
+            Palette::Tableau,
+            Palette::Pastel,
+            Palette::OkabeIto,
+        ] {
+            let colors = palette.colors();
+            for (i, a) in colors.iter().enumerate() {
+                assert!(!colors[i + 1..].contains(a), "{palette:?} repeats {a:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn palette_custom() {
+        let colors = [Color::White, Color::Black];
+        let palette = Palette::Custom(&colors);
+        assert_eq!(palette.len(), 2);
+        assert_eq!(palette.color(0), Color::White);
+        assert_eq!(palette.color(5), Color::Black);
+    }
+
+    #[test]
+    fn palette_custom_empty_resets() {
+        let palette = Palette::Custom(&[]);
+        assert!(palette.is_empty());
+        assert_eq!(palette.color(7), Color::Reset);
+    }
+}
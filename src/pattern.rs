@@ -0,0 +1,196 @@
+//! Fill patterns for telling slices apart without color.
+//!
+//! On monochrome terminals, with `NO_COLOR` set, or for readers with color
+//! vision deficiency, slices that differ only by color look the same. A
+//! [`FillPattern`] gives each slice its own texture: a distinct glyph in
+//! standard resolution, or a distinct dot mask in braille resolution. The
+//! legend marker shows the same glyph, so legend entries still match their
+//! slices.
+//!
+//! # Examples
+//!
+//! ```
+//! use ratatui::style::Color;
+//! use tui_piechart::{FillPattern, PieChart, PieSlice};
+//!
+//! // Assign a distinct pattern to every slice automatically
+//! let chart = PieChart::new(vec![
+//!     PieSlice::new("Rust", 45.0, Color::Red),
+//!     PieSlice::new("Go", 30.0, Color::Blue),
+//!     PieSlice::new("Python", 25.0, Color::Green),
+//! ])
+//! .auto_patterns(true);
+//!
+//! // Or pick a pattern for a single slice
+//! let slice = PieSlice::new("Unknown", 5.0, Color::Gray).pattern(FillPattern::LightShade);
+//! ```
+
+use crate::symbols;
+
+/// The fill texture of a pie slice.
+///
+/// In [`Resolution::Standard`](crate::Resolution::Standard) each pattern is a
+/// glyph drawn in every cell of the slice. In
+/// [`Resolution::Braille`](crate::Resolution::Braille) each pattern is a mask
+/// that decides which braille dots of the slice are raised.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum FillPattern {
+    /// Solid fill using the chart's pie character (default)
+    #[default]
+    Solid,
+
+    /// Dense shading (`▓`, 75% of braille dots)
+    DarkShade,
+
+    /// Medium shading (`▒`, checkerboard of braille dots)
+    MediumShade,
+
+    /// Light shading (`░`, 25% of braille dots)
+    LightShade,
+
+    /// Diagonal hatching (`╱`, diagonal lines of braille dots)
+    DiagonalHatch,
+
+    /// Cross hatching (`╳`, crossing diagonal lines of braille dots)
+    CrossHatch,
+
+    /// Sparse dots (`·`, one braille dot in eight)
+    Dotted,
+
+    /// A custom glyph. Braille rendering treats this as a solid fill.
+    Char(char),
+}
+
+impl FillPattern {
+    /// Patterns assigned by [`PieChart::auto_patterns`](crate::PieChart::auto_patterns),
+    /// ordered from densest to sparsest so neighbouring slices contrast well.
+    pub const DISTINCT: [Self; 7] = [
+        Self::Solid,
+        Self::DiagonalHatch,
+        Self::MediumShade,
+        Self::Dotted,
+        Self::DarkShade,
+        Self::CrossHatch,
+        Self::LightShade,
+    ];
+
+    /// Returns the automatically assigned pattern for the slice at `index`.
+    ///
+    /// Wraps around after [`DISTINCT`](Self::DISTINCT) is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::FillPattern;
+    ///
+    /// assert_eq!(FillPattern::nth(0), FillPattern::Solid);
+    /// assert_eq!(FillPattern::nth(FillPattern::DISTINCT.len()), FillPattern::Solid);
+    /// ```
+    #[must_use]
+    pub const fn nth(index: usize) -> Self {
+        Self::DISTINCT[index % Self::DISTINCT.len()]
+    }
+
+    /// Returns the glyph for this pattern, or `None` for [`Solid`](Self::Solid),
+    /// which uses the chart's own pie character and legend marker.
+    #[must_use]
+    pub const fn glyph(self) -> Option<char> {
+        match self {
+            Self::Solid => None,
+            Self::DarkShade => Some(symbols::PIE_CHAR_DARK),
+            Self::MediumShade => Some(symbols::PIE_CHAR_SHADE),
+            Self::LightShade => Some(symbols::PIE_CHAR_LIGHT),
+            Self::DiagonalHatch => Some(symbols::PIE_CHAR_DIAGONAL),
+            Self::CrossHatch => Some(symbols::PIE_CHAR_CROSS_HATCH),
+            Self::Dotted => Some(symbols::PIE_CHAR_DOT),
+            Self::Char(c) => Some(c),
+        }
+    }
+
+    /// Returns `true` if the braille dot at (`x`, `y`) is raised by this
+    /// pattern. Coordinates are in dots, relative to the pie area.
+    #[must_use]
+    pub const fn covers_dot(self, x: u16, y: u16) -> bool {
+        match self {
+            Self::Solid | Self::Char(_) => true,
+            Self::DarkShade => !(x % 2 == 0 && y % 2 == 0),
+            Self::MediumShade => (x + y) % 2 == 0,
+            Self::LightShade => x % 2 == 0 && y % 2 == 0,
+            Self::DiagonalHatch => (x + y) % 3 == 0,
+            Self::CrossHatch => (x + y) % 4 == 0 || (x + 4 - y % 4) % 4 == 0,
+            Self::Dotted => x % 2 == 0 && y % 4 == 0,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unnameable_test_items)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill_pattern_default_is_solid() {
+        assert_eq!(FillPattern::default(), FillPattern::Solid);
+    }
+
+    #[test]
+    fn fill_pattern_nth_wraps() {
+        let len = FillPattern::DISTINCT.len();
+        for i in 0..len {
+            assert_eq!(FillPattern::nth(i), FillPattern::nth(i + len));
+        }
+    }
+
+    #[test]
+    fn fill_pattern_distinct_glyphs_are_unique() {
+        let glyphs: Vec<_> = FillPattern::DISTINCT.iter().map(|p| p.glyph()).collect();
+        for (i, g) in glyphs.iter().enumerate() {
+            assert!(!glyphs[i + 1..].contains(g), "duplicate glyph {g:?}");
+        }
+    }
+
+    #[test]
+    fn fill_pattern_solid_has_no_glyph() {
+        assert_eq!(FillPattern::Solid.glyph(), None);
+        assert_eq!(FillPattern::Char('x').glyph(), Some('x'));
+    }
+
+    #[test]
+    fn fill_pattern_dot_density_ordering() {
+        // Count raised dots over one 4x4 tile; shades must get sparser.
+        let density = |p: FillPattern| {
+            (0..4u16)
+                .flat_map(|y| (0..4u16).map(move |x| (x, y)))
+                .filter(|&(x, y)| p.covers_dot(x, y))
+                .count()
+        };
+        assert_eq!(density(FillPattern::Solid), 16);
+        assert!(density(FillPattern::DarkShade) > density(FillPattern::MediumShade));
+        assert!(density(FillPattern::MediumShade) > density(FillPattern::LightShade));
+        assert!(density(FillPattern::LightShade) > density(FillPattern::Dotted));
+        assert!(density(FillPattern::Dotted) > 0);
+    }
+
+    #[test]
+    fn fill_pattern_masks_are_distinct() {
+        let mask = |p: FillPattern| {
+            (0..12u16)
+                .flat_map(|y| (0..12u16).map(move |x| (x, y)))
+                .map(|(x, y)| p.covers_dot(x, y))
+                .collect::<Vec<_>>()
+        };
+        let masks: Vec<_> = FillPattern::DISTINCT.iter().map(|&p| mask(p)).collect();
+        for (i, m) in masks.iter().enumerate() {
+            assert!(
+                !masks[i + 1..].contains(m),
+                "{:?}",
+                FillPattern::DISTINCT[i]
+            );
+        }
+    }
+}
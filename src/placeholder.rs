@@ -0,0 +1,145 @@
+//! Placeholder shown when a chart has nothing to draw.
+//!
+//! A chart with no slices, or whose values add up to zero, has no pie to
+//! draw. By default it stays blank. A [`Placeholder`] fills the pie area
+//! instead with a short message, an outline ring, or both, so an empty chart
+//! is visibly empty rather than looking like it never rendered.
+//!
+//! # Examples
+//!
+//! ```
+//! use ratatui::style::{Color, Style};
+//! use tui_piechart::{PieChart, Placeholder};
+//!
+//! let chart = PieChart::new(vec![]).placeholder(
+//!     Placeholder::new("Waiting for data…").ring(Style::new().fg(Color::DarkGray)),
+//! );
+//! ```
+
+use ratatui::style::{Modifier, Style};
+
+use crate::symbols;
+
+/// What to draw in the pie area when a chart has nothing to draw.
+///
+/// The default placeholder shows a dim "No data" message, no ring, and no
+/// legend.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::style::{Color, Style};
+/// use tui_piechart::Placeholder;
+///
+/// // Only a ring
+/// let ring = Placeholder::new("").ring(Style::new().fg(Color::DarkGray));
+///
+/// // A message, with the legend listing every slice at 0%
+/// let zero = Placeholder::new("All zero").show_legend(true);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Placeholder<'a> {
+    /// Message drawn in the middle of the pie area; empty for none
+    pub(crate) text: &'a str,
+    /// Style of the message
+    pub(crate) text_style: Style,
+    /// Style of the outline ring, or `None` for no ring
+    pub(crate) ring: Option<Style>,
+    /// Character used to draw the ring in standard resolution
+    pub(crate) ring_char: char,
+    /// Whether the legend is still drawn, listing slices at 0%
+    pub(crate) show_legend: bool,
+}
+
+impl Default for Placeholder<'_> {
+    fn default() -> Self {
+        Self::new("No data")
+    }
+}
+
+impl<'a> Placeholder<'a> {
+    /// Creates a placeholder with the given message in a dim style.
+    ///
+    /// Pass an empty string to draw no message.
+    #[must_use]
+    pub const fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            text_style: Style::new().add_modifier(Modifier::DIM),
+            ring: None,
+            ring_char: symbols::PIE_CHAR_DOT,
+            show_legend: false,
+        }
+    }
+
+    /// Sets the message.
+    #[must_use]
+    pub const fn text(mut self, text: &'a str) -> Self {
+        self.text = text;
+        self
+    }
+
+    /// Sets the style of the message.
+    #[must_use]
+    pub fn text_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.text_style = style.into();
+        self
+    }
+
+    /// Draws an outline ring of the pie in the given style.
+    #[must_use]
+    pub fn ring<S: Into<Style>>(mut self, style: S) -> Self {
+        self.ring = Some(style.into());
+        self
+    }
+
+    /// Sets the character used to draw the ring in standard resolution.
+    ///
+    /// Braille resolution always draws the ring with braille dots.
+    #[must_use]
+    pub const fn ring_char(mut self, c: char) -> Self {
+        self.ring_char = c;
+        self
+    }
+
+    /// Sets whether the legend is drawn while the placeholder is shown.
+    ///
+    /// When enabled, a chart whose values add up to zero still lists its
+    /// slices, each at 0%. A chart without slices has no legend entries.
+    #[must_use]
+    pub const fn show_legend(mut self, show: bool) -> Self {
+        self.show_legend = show;
+        self
+    }
+}
+
+#[cfg(test)]
+#[allow(unnameable_test_items)]
+mod tests {
+    use super::*;
+    use ratatui::style::Color;
+
+    #[test]
+    fn placeholder_default() {
+        let placeholder = Placeholder::default();
+        assert_eq!(placeholder.text, "No data");
+        assert!(placeholder.text_style.add_modifier.contains(Modifier::DIM));
+        assert_eq!(placeholder.ring, None);
+        assert!(!placeholder.show_legend);
+    }
+
+    #[test]
+    fn placeholder_builder_chaining() {
+        let placeholder = Placeholder::new("Empty")
+            .text("Nothing yet")
+            .text_style(Color::Gray)
+            .ring(Color::DarkGray)
+            .ring_char('○')
+            .show_legend(true);
+        assert_eq!(placeholder.text, "Nothing yet");
+        assert_eq!(placeholder.text_style, Style::new().fg(Color::Gray));
+        assert_eq!(placeholder.ring, Some(Style::new().fg(Color::DarkGray)));
+        assert_eq!(placeholder.ring_char, '○');
+        assert!(placeholder.show_legend);
+    }
+}
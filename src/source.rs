@@ -0,0 +1,280 @@
+//! Data sources for pie charts.
+//!
+//! A [`PieChart`](crate::PieChart) reads its slices through the
+//! [`PieDataSource`] trait. `Vec<PieSlice>` and `[PieSlice]` implement it, so
+//! [`PieChart::new`](crate::PieChart::new) works as before, but any type that
+//! can report a label and a value per index can be charted directly, without
+//! building a list of slices first. While it renders, the chart keeps only the
+//! index and value of each slice, so that it can sort them.
+//!
+//! # Examples
+//!
+//! ```
+//! use ratatui::style::{Color, Style};
+//! use tui_piechart::{PieChart, PieDataSource};
+//!
+//! struct Language {
+//!     name: String,
+//!     lines: u64,
+//! }
+//!
+//! struct Languages<'a>(&'a [Language]);
+//!
+//! impl PieDataSource for Languages<'_> {
+//!     fn len(&self) -> usize {
+//!         self.0.len()
+//!     }
+//!
+//!     fn label(&self, index: usize) -> &str {
+//!         &self.0[index].name
+//!     }
+//!
+//!     #[allow(clippy::cast_precision_loss)]
+//!     fn value(&self, index: usize) -> f64 {
+//!         self.0[index].lines as f64
+//!     }
+//! }
+//!
+//! let languages = vec![
+//!     Language { name: "Rust".into(), lines: 4500 },
+//!     Language { name: "Go".into(), lines: 3000 },
+//! ];
+//! let chart = PieChart::from_source(Languages(&languages));
+//! ```
+
+use ratatui::style::Style;
+
+use crate::pattern::FillPattern;
+use crate::PieSlice;
+
+/// Slice data read by a [`PieChart`](crate::PieChart).
+///
+/// Indices run from `0` to `len() - 1`; the chart never asks for an index
+/// outside that range. Only [`len`](Self::len), [`label`](Self::label) and
+/// [`value`](Self::value) are required. The remaining methods override the
+/// chart's defaults for a single slice and return "no override" unless
+/// implemented.
+pub trait PieDataSource {
+    /// Returns the number of slices.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there are no slices.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the label of the slice at `index`.
+    fn label(&self, index: usize) -> &str;
+
+    /// Returns the value of the slice at `index`.
+    fn value(&self, index: usize) -> f64;
+
+    /// Returns the style of the slice at `index`.
+    ///
+    /// Slices whose style has no foreground color are colored from the
+    /// chart's palette.
+    fn style(&self, index: usize) -> Style {
+        let _ = index;
+        Style::default()
+    }
+
+    /// Returns the style patched onto the legend entry of the slice at
+    /// `index`, if any.
+    fn legend_style(&self, index: usize) -> Option<Style> {
+        let _ = index;
+        None
+    }
+
+    /// Returns the fill pattern of the slice at `index`, if any.
+    fn pattern(&self, index: usize) -> Option<FillPattern> {
+        let _ = index;
+        None
+    }
+
+    /// Returns the character used to draw the slice at `index`, if any.
+    fn pie_char(&self, index: usize) -> Option<char> {
+        let _ = index;
+        None
+    }
+
+    /// Returns the legend marker of the slice at `index`, if any.
+    fn legend_marker(&self, index: usize) -> Option<&str> {
+        let _ = index;
+        None
+    }
+}
+
+impl PieDataSource for [PieSlice<'_>] {
+    fn len(&self) -> usize {
+        <[PieSlice]>::len(self)
+    }
+
+    fn label(&self, index: usize) -> &str {
+        self[index].label
+    }
+
+    fn value(&self, index: usize) -> f64 {
+        self[index].value
+    }
+
+    fn style(&self, index: usize) -> Style {
+        let slice = &self[index];
+        match slice.color {
+            Some(color) => Style::default().fg(color).patch(slice.style),
+            None => slice.style,
+        }
+    }
+
+    fn legend_style(&self, index: usize) -> Option<Style> {
+        self[index].legend_style
+    }
+
+    fn pattern(&self, index: usize) -> Option<FillPattern> {
+        self[index].pattern
+    }
+
+    fn pie_char(&self, index: usize) -> Option<char> {
+        self[index].pie_char
+    }
+
+    fn legend_marker(&self, index: usize) -> Option<&str> {
+        self[index].legend_marker
+    }
+}
+
+impl PieDataSource for Vec<PieSlice<'_>> {
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    fn label(&self, index: usize) -> &str {
+        self.as_slice().label(index)
+    }
+
+    fn value(&self, index: usize) -> f64 {
+        self.as_slice().value(index)
+    }
+
+    fn style(&self, index: usize) -> Style {
+        self.as_slice().style(index)
+    }
+
+    fn legend_style(&self, index: usize) -> Option<Style> {
+        self.as_slice().legend_style(index)
+    }
+
+    fn pattern(&self, index: usize) -> Option<FillPattern> {
+        self.as_slice().pattern(index)
+    }
+
+    fn pie_char(&self, index: usize) -> Option<char> {
+        self.as_slice().pie_char(index)
+    }
+
+    fn legend_marker(&self, index: usize) -> Option<&str> {
+        self.as_slice().legend_marker(index)
+    }
+}
+
+impl<T: PieDataSource + ?Sized> PieDataSource for &T {
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    fn label(&self, index: usize) -> &str {
+        (**self).label(index)
+    }
+
+    fn value(&self, index: usize) -> f64 {
+        (**self).value(index)
+    }
+
+    fn style(&self, index: usize) -> Style {
+        (**self).style(index)
+    }
+
+    fn legend_style(&self, index: usize) -> Option<Style> {
+        (**self).legend_style(index)
+    }
+
+    fn pattern(&self, index: usize) -> Option<FillPattern> {
+        (**self).pattern(index)
+    }
+
+    fn pie_char(&self, index: usize) -> Option<char> {
+        (**self).pie_char(index)
+    }
+
+    fn legend_marker(&self, index: usize) -> Option<&str> {
+        (**self).legend_marker(index)
+    }
+}
+
+#[cfg(test)]
+#[allow(unnameable_test_items)]
+mod tests {
+    use super::*;
+    use ratatui::style::{Color, Modifier};
+
+    #[test]
+    fn slices_as_source() {
+        let slices = vec![
+            PieSlice::new("Rust", 45.0, Color::Red).pattern(FillPattern::Dotted),
+            PieSlice::auto("Go", 30.0)
+                .pie_char('x')
+                .legend_marker("→")
+                .legend_style(Modifier::BOLD),
+        ];
+        assert_eq!(PieDataSource::len(&slices), 2);
+        assert!(!PieDataSource::is_empty(&slices));
+        assert_eq!(slices.label(1), "Go");
+        assert!((slices.value(0) - 45.0).abs() < f64::EPSILON);
+        assert_eq!(slices.pattern(0), Some(FillPattern::Dotted));
+        assert_eq!(slices.pie_char(1), Some('x'));
+        assert_eq!(slices.legend_marker(1), Some("→"));
+        assert_eq!(
+            slices.legend_style(1),
+            Some(Style::new().add_modifier(Modifier::BOLD))
+        );
+    }
+
+    #[test]
+    fn slice_style_includes_color() {
+        let slices = [
+            PieSlice::new("Rust", 45.0, Color::Red).style(Modifier::BOLD),
+            PieSlice::auto("Go", 30.0),
+            PieSlice::new("C", 30.0, Color::Red).style(Color::Blue),
+        ];
+        let source: &[PieSlice] = &slices;
+        assert_eq!(
+            source.style(0),
+            Style::new().fg(Color::Red).add_modifier(Modifier::BOLD)
+        );
+        assert_eq!(source.style(1).fg, None);
+        assert_eq!(source.style(2).fg, Some(Color::Blue));
+    }
+
+    #[test]
+    fn default_methods_have_no_overrides() {
+        struct Values(Vec<String>, Vec<f64>);
+        impl PieDataSource for Values {
+            fn len(&self) -> usize {
+                self.1.len()
+            }
+            fn label(&self, index: usize) -> &str {
+                &self.0[index]
+            }
+            fn value(&self, index: usize) -> f64 {
+                self.1[index]
+            }
+        }
+
+        let values = Values(vec![], vec![]);
+        assert!(values.is_empty());
+        assert_eq!(values.style(0), Style::default());
+        assert_eq!(values.legend_style(0), None);
+        assert_eq!(values.pattern(0), None);
+        assert_eq!(values.pie_char(0), None);
+        assert_eq!(values.legend_marker(0), None);
+    }
+}
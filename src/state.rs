@@ -0,0 +1,146 @@
+//! Widget state for pie charts.
+//!
+//! Rendered as a [`StatefulWidget`](ratatui::widgets::StatefulWidget), a
+//! [`PieChart`](crate::PieChart) keeps its selection and legend scroll
+//! position in a [`PieChartState`] owned by the application, the same way
+//! ratatui's `List` uses `ListState`.
+//!
+//! # Examples
+//!
+//! ```
+//! use ratatui::buffer::Buffer;
+//! use ratatui::layout::Rect;
+//! use ratatui::widgets::StatefulWidget;
+//! use tui_piechart::{PieChart, PieChartState, PieSlice};
+//!
+//! let chart = PieChart::new(vec![PieSlice::auto("Rust", 45.0), PieSlice::auto("Go", 30.0)]);
+//! let mut state = PieChartState::new().with_selected(Some(1));
+//!
+//! let area = Rect::new(0, 0, 40, 20);
+//! let mut buf = Buffer::empty(area);
+//! StatefulWidget::render(&chart, area, &mut buf, &mut state);
+//! ```
+
+/// Selection and legend scroll position of a [`PieChart`](crate::PieChart).
+///
+/// The selected slice is identified by its index in the chart's data, so the
+/// selection survives sorting. Its legend entry is drawn with the chart's
+/// [`highlight_style`](crate::PieChart::highlight_style), and a scrolled
+/// legend keeps it in view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct PieChartState {
+    /// Index of the selected slice in the chart's data
+    pub(crate) selected: Option<usize>,
+    /// Position of the first legend entry shown in a scrolled legend
+    pub(crate) legend_offset: usize,
+}
+
+impl PieChartState {
+    /// Creates a state with no selection and an unscrolled legend.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            selected: None,
+            legend_offset: 0,
+        }
+    }
+
+    /// Sets the selected slice.
+    #[must_use]
+    pub const fn with_selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the legend scroll offset.
+    #[must_use]
+    pub const fn with_legend_offset(mut self, offset: usize) -> Self {
+        self.legend_offset = offset;
+        self
+    }
+
+    /// Returns the index of the selected slice, if any.
+    #[must_use]
+    pub const fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Selects the slice at `index` in the chart's data, or clears the
+    /// selection with `None`.
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+    }
+
+    /// Returns the position of the first legend entry shown when the legend
+    /// is scrolled.
+    ///
+    /// Rendering clamps the offset to the number of entries and moves it to
+    /// keep the selected slice visible.
+    #[must_use]
+    pub const fn legend_offset(&self) -> usize {
+        self.legend_offset
+    }
+
+    /// Scrolls the legend up by `amount` entries.
+    pub fn scroll_legend_up(&mut self, amount: usize) {
+        self.legend_offset = self.legend_offset.saturating_sub(amount);
+    }
+
+    /// Scrolls the legend down by `amount` entries.
+    pub fn scroll_legend_down(&mut self, amount: usize) {
+        self.legend_offset = self.legend_offset.saturating_add(amount);
+    }
+
+    /// Clamps the offset so that `visible` entries out of `len` are shown,
+    /// and moves it just enough to show the entry at `selected`, if any.
+    pub(crate) fn scroll_to(&mut self, len: usize, visible: usize, selected: Option<usize>) {
+        let visible = visible.max(1);
+        if let Some(position) = selected {
+            if position < self.legend_offset {
+                self.legend_offset = position;
+            } else if position >= self.legend_offset + visible {
+                self.legend_offset = position + 1 - visible;
+            }
+        }
+        self.legend_offset = self.legend_offset.min(len.saturating_sub(visible));
+    }
+}
+
+#[cfg(test)]
+#[allow(unnameable_test_items)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_builders_and_scrolling() {
+        let mut state = PieChartState::new()
+            .with_selected(Some(3))
+            .with_legend_offset(2);
+        assert_eq!(state.selected(), Some(3));
+        assert_eq!(state.legend_offset(), 2);
+        state.scroll_legend_up(5);
+        assert_eq!(state.legend_offset(), 0);
+        state.scroll_legend_down(4);
+        assert_eq!(state.legend_offset(), 4);
+        state.select(None);
+        assert_eq!(state, PieChartState::new().with_legend_offset(4));
+    }
+
+    #[test]
+    fn scroll_to_follows_selection_and_clamps() {
+        let mut state = PieChartState::new().with_legend_offset(8);
+        state.scroll_to(10, 4, None);
+        assert_eq!(state.legend_offset, 6);
+        state.scroll_to(10, 4, Some(1));
+        assert_eq!(state.legend_offset, 1);
+        state.scroll_to(10, 4, Some(7));
+        assert_eq!(state.legend_offset, 4);
+        state.scroll_to(3, 4, Some(2));
+        assert_eq!(state.legend_offset, 0);
+    }
+}
@@ -170,6 +170,8 @@ pie_symbols! {
     PIE_CHAR_SQUARE_BOX: '▣', "square box",
     PIE_CHAR_ASTERISM: '※', "asterism",
     PIE_CHAR_HORIZONTAL_BAR: '▰', "horizontal bar",
+    PIE_CHAR_DIAGONAL: '╱', "diagonal line",
+    PIE_CHAR_CROSS_HATCH: '╳', "diagonal cross",
 }
 
 // ============================================================================
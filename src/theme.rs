@@ -0,0 +1,328 @@
+//! Reusable chart themes.
+//!
+//! A [`PieChartTheme`] bundles the visual options that are usually shared by
+//! every chart in an application: symbols, border and title styles, palette,
+//! and legend placement. Apply one with [`PieChart::theme`](crate::PieChart::theme)
+//! instead of repeating the same builder calls on each chart.
+//!
+//! # Examples
+//!
+//! ```
+//! use tui_piechart::{PieChart, PieSlice};
+//! use tui_piechart::theme::PieChartTheme;
+//!
+//! let theme = PieChartTheme::dark();
+//!
+//! let chart = PieChart::new(vec![
+//!     PieSlice::auto("Rust", 45.0),
+//!     PieSlice::auto("Go", 30.0),
+//! ])
+//! .theme(&theme)
+//! .block(theme.block("Languages"));
+//! ```
+
+use ratatui::style::{Color, Style};
+use ratatui::widgets::Block;
+
+use crate::border_style::BorderStyle;
+use crate::legend::{LegendAlignment, LegendLayout, LegendPosition};
+use crate::palette::Palette;
+use crate::symbols;
+use crate::title::TitleStyle;
+
+/// A bundle of visual options applied to a [`PieChart`](crate::PieChart).
+///
+/// Start from one of the built-in themes ([`dark`](Self::dark),
+/// [`light`](Self::light), [`high_contrast`](Self::high_contrast),
+/// [`minimal_ascii`](Self::minimal_ascii)) or from [`default`](Self::default),
+/// which matches the chart defaults, and adjust it with the builder methods.
+///
+/// # Examples
+///
+/// ```
+/// use tui_piechart::{LegendPosition, Palette};
+/// use tui_piechart::border_style::BorderStyle;
+/// use tui_piechart::theme::PieChartTheme;
+///
+/// let theme = PieChartTheme::light()
+///     .palette(Palette::Tableau)
+///     .border_style(Some(BorderStyle::Rounded))
+///     .legend_position(LegendPosition::Bottom);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PieChartTheme {
+    /// Base style for the chart and its block
+    pub(crate) style: Style,
+    /// Character used to draw the pie
+    pub(crate) pie_char: char,
+    /// Marker used for legend items
+    pub(crate) legend_marker: &'static str,
+    /// Border style of the block installed by the theme, if any
+    pub(crate) border_style: Option<BorderStyle>,
+    /// Font style applied to block titles created by the theme
+    pub(crate) title_style: TitleStyle,
+    /// Palette for slices without an explicit color
+    pub(crate) palette: Palette<'static>,
+    /// Whether slices get distinct fill patterns automatically
+    pub(crate) auto_patterns: bool,
+    /// Position of the legend
+    pub(crate) legend_position: LegendPosition,
+    /// Layout of the legend
+    pub(crate) legend_layout: LegendLayout,
+    /// Alignment of legend items
+    pub(crate) legend_alignment: LegendAlignment,
+}
+
+impl Default for PieChartTheme {
+    /// Returns a theme matching the default `PieChart` appearance, without a
+    /// border.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PieChartTheme {
+    /// Creates a theme matching the default `PieChart` appearance, without a
+    /// border.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            style: Style::new(),
+            pie_char: symbols::PIE_CHAR,
+            legend_marker: symbols::LEGEND_MARKER,
+            border_style: None,
+            title_style: TitleStyle::Normal,
+            palette: Palette::Categorical,
+            auto_patterns: false,
+            legend_position: LegendPosition::Right,
+            legend_layout: LegendLayout::Vertical,
+            legend_alignment: LegendAlignment::Left,
+        }
+    }
+
+    /// A theme for dark terminal backgrounds.
+    ///
+    /// Light gray text, muted Tableau colors, rounded borders and bold titles.
+    #[must_use]
+    pub const fn dark() -> Self {
+        Self::new()
+            .style(Style::new().fg(Color::Gray))
+            .legend_marker(symbols::LEGEND_MARKER_CIRCLE)
+            .border_style(Some(BorderStyle::Rounded))
+            .title_style(TitleStyle::Bold)
+            .palette(Palette::Tableau)
+    }
+
+    /// A theme for light terminal backgrounds.
+    ///
+    /// Black text, Okabe–Ito colors that stay saturated on white, and
+    /// standard borders.
+    #[must_use]
+    pub const fn light() -> Self {
+        Self::new()
+            .style(Style::new().fg(Color::Black))
+            .border_style(Some(BorderStyle::Standard))
+            .palette(Palette::OkabeIto)
+    }
+
+    /// A high-contrast theme.
+    ///
+    /// White on black, bright named colors, thick borders, and distinct fill
+    /// patterns so slices never rely on color alone.
+    #[must_use]
+    pub const fn high_contrast() -> Self {
+        Self::new()
+            .style(Style::new().fg(Color::White).bg(Color::Black))
+            .pie_char(symbols::PIE_CHAR_BLOCK)
+            .border_style(Some(BorderStyle::Thick))
+            .palette(Palette::Categorical)
+            .auto_patterns(true)
+    }
+
+    /// A theme that only draws ASCII characters.
+    ///
+    /// For terminals and fonts without Unicode symbol support. Fill patterns
+    /// are left off because their glyphs are not ASCII.
+    #[must_use]
+    pub const fn minimal_ascii() -> Self {
+        Self::new()
+            .pie_char('#')
+            .legend_marker("*")
+            .border_style(Some(BorderStyle::Ascii))
+    }
+
+    /// Sets the base style.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the character used to draw the pie.
+    #[must_use]
+    pub const fn pie_char(mut self, c: char) -> Self {
+        self.pie_char = c;
+        self
+    }
+
+    /// Sets the marker used for legend items.
+    #[must_use]
+    pub const fn legend_marker(mut self, marker: &'static str) -> Self {
+        self.legend_marker = marker;
+        self
+    }
+
+    /// Sets the border style, or `None` for no border.
+    #[must_use]
+    pub const fn border_style(mut self, border_style: Option<BorderStyle>) -> Self {
+        self.border_style = border_style;
+        self
+    }
+
+    /// Sets the font style used for titles created by [`block`](Self::block).
+    #[must_use]
+    pub const fn title_style(mut self, title_style: TitleStyle) -> Self {
+        self.title_style = title_style;
+        self
+    }
+
+    /// Sets the palette for slices without an explicit color.
+    #[must_use]
+    pub const fn palette(mut self, palette: Palette<'static>) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Sets whether slices get distinct fill patterns automatically.
+    #[must_use]
+    pub const fn auto_patterns(mut self, enabled: bool) -> Self {
+        self.auto_patterns = enabled;
+        self
+    }
+
+    /// Sets the position of the legend.
+    #[must_use]
+    pub const fn legend_position(mut self, position: LegendPosition) -> Self {
+        self.legend_position = position;
+        self
+    }
+
+    /// Sets the layout of the legend.
+    #[must_use]
+    pub const fn legend_layout(mut self, layout: LegendLayout) -> Self {
+        self.legend_layout = layout;
+        self
+    }
+
+    /// Sets the alignment of legend items.
+    #[must_use]
+    pub const fn legend_alignment(mut self, alignment: LegendAlignment) -> Self {
+        self.legend_alignment = alignment;
+        self
+    }
+
+    /// Creates a block with this theme's border style, base style, and a
+    /// title rendered in the theme's title style.
+    ///
+    /// Themes without a border style produce a borderless block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_piechart::theme::PieChartTheme;
+    ///
+    /// let block = PieChartTheme::dark().block("Languages");
+    /// ```
+    #[must_use]
+    pub fn block(&self, title: &str) -> Block<'static> {
+        self.untitled_block().title(self.title_style.apply(title))
+    }
+
+    /// Creates a block with this theme's border style and base style.
+    pub(crate) fn untitled_block(&self) -> Block<'static> {
+        self.border_style
+            .map_or_else(Block::new, BorderStyle::block)
+            .style(self.style)
+    }
+}
+
+#[cfg(test)]
+#[allow(unnameable_test_items)]
+mod tests {
+    use super::*;
+    use crate::{PieChart, PieSlice};
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::Widget;
+
+    #[test]
+    fn theme_default_matches_new() {
+        assert_eq!(PieChartTheme::default(), PieChartTheme::new());
+        assert_eq!(PieChartTheme::new().border_style, None);
+    }
+
+    #[test]
+    fn theme_builtins_differ() {
+        let themes = [
+            PieChartTheme::dark(),
+            PieChartTheme::light(),
+            PieChartTheme::high_contrast(),
+            PieChartTheme::minimal_ascii(),
+        ];
+        for (i, a) in themes.iter().enumerate() {
+            for b in &themes[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn theme_minimal_ascii_is_ascii() {
+        let theme = PieChartTheme::minimal_ascii();
+        assert!(theme.pie_char.is_ascii());
+        assert!(theme.legend_marker.is_ascii());
+        assert_eq!(theme.border_style, Some(BorderStyle::Ascii));
+        assert!(!theme.auto_patterns);
+    }
+
+    #[test]
+    fn theme_high_contrast_uses_patterns() {
+        assert!(PieChartTheme::high_contrast().auto_patterns);
+    }
+
+    #[test]
+    fn theme_builder_chaining() {
+        let theme = PieChartTheme::new()
+            .pie_char('█')
+            .legend_marker("→")
+            .palette(Palette::Pastel)
+            .legend_position(LegendPosition::Bottom)
+            .legend_layout(LegendLayout::Horizontal)
+            .legend_alignment(LegendAlignment::Center)
+            .title_style(TitleStyle::Italic);
+        assert_eq!(theme.pie_char, '█');
+        assert_eq!(theme.legend_marker, "→");
+        assert_eq!(theme.palette, Palette::Pastel);
+        assert_eq!(theme.legend_position, LegendPosition::Bottom);
+        assert_eq!(theme.legend_layout, LegendLayout::Horizontal);
+        assert_eq!(theme.legend_alignment, LegendAlignment::Center);
+        assert_eq!(theme.title_style, TitleStyle::Italic);
+    }
+
+    #[test]
+    fn theme_block_applies_title_style() {
+        let theme = PieChartTheme::dark();
+        let chart = PieChart::new(vec![PieSlice::auto("A", 1.0)])
+            .block(theme.block("Stats"))
+            .theme(&theme);
+        let area = Rect::new(0, 0, 20, 5);
+        let mut buffer = Buffer::empty(area);
+        chart.render(area, &mut buffer);
+
+        let top: String = (0..area.width).map(|x| buffer[(x, 0)].symbol()).collect();
+        assert_eq!(
+            top,
+            format!("╭{}{}╮", TitleStyle::Bold.apply("Stats"), "─".repeat(13))
+        );
+    }
+}
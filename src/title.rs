@@ -187,6 +187,11 @@ impl<T: Into<String>> From<T> for Title {
 /// - **Center**: Always centered
 /// - **End**: Right in LTR languages, left in RTL languages
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum TitleAlignment {
     /// Start-aligned title (left in LTR, right in RTL)
     ///
@@ -208,6 +213,14 @@ pub enum TitleAlignment {
     End,
 }
 
+crate::macros::option::option_enum! {
+    TitleAlignment {
+        Start => "start",
+        Center => "center",
+        End => "end",
+    }
+}
+
 impl From<TitleAlignment> for Alignment {
     fn from(alignment: TitleAlignment) -> Self {
         match alignment {
@@ -240,6 +253,11 @@ impl From<TitleAlignment> for Alignment {
 /// - Top-Start, Top-Center, Top-End
 /// - Bottom-Start, Bottom-Center, Bottom-End
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum TitlePosition {
     /// Title at the top (default)
     ///
@@ -256,6 +274,13 @@ pub enum TitlePosition {
     Bottom,
 }
 
+crate::macros::option::option_enum! {
+    TitlePosition {
+        Top => "top",
+        Bottom => "bottom",
+    }
+}
+
 /// Font style for block titles using Unicode character variants.
 ///
 /// Converts regular ASCII text to different Unicode character sets to achieve
@@ -279,6 +304,11 @@ pub enum TitlePosition {
 /// - Terminal font must support the Unicode characters (most modern terminals do)
 /// - Some styles may not render identically across different fonts
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum TitleStyle {
     /// Normal/regular text (default) - no transformation applied
     #[default]
@@ -330,6 +360,21 @@ pub enum TitleStyle {
     Monospace,
 }
 
+crate::macros::option::option_enum! {
+    TitleStyle {
+        Normal => "normal",
+        Bold => "bold",
+        Italic => "italic",
+        BoldItalic => "bold-italic",
+        Script => "script",
+        BoldScript => "bold-script",
+        SansSerif => "sans-serif",
+        BoldSansSerif => "bold-sans-serif",
+        ItalicSansSerif => "italic-sans-serif",
+        Monospace => "monospace",
+    }
+}
+
 impl TitleStyle {
     /// Apply this style to the given text.
     ///
@@ -874,4 +919,24 @@ mod tests {
             assert_eq!(result.chars().count(), 3, "{variant:?} changed char count");
         }
     }
+
+    #[test]
+    fn title_options_round_trip_through_strings() {
+        for style in TitleStyle::iter() {
+            assert_eq!(style.to_string().parse(), Ok(style));
+        }
+        for alignment in TitleAlignment::iter() {
+            assert_eq!(alignment.to_string().parse(), Ok(alignment));
+        }
+        for position in TitlePosition::iter() {
+            assert_eq!(position.to_string().parse(), Ok(position));
+        }
+    }
+
+    #[test]
+    fn title_style_parse() {
+        assert_eq!(TitleStyle::BoldSansSerif.to_string(), "bold-sans-serif");
+        assert_eq!("Bold_Italic".parse(), Ok(TitleStyle::BoldItalic));
+        assert!("gothic".parse::<TitleStyle>().is_err());
+    }
 }
@@ -0,0 +1,96 @@
+#![cfg(feature = "serde")]
+
+use ratatui::style::{Color, Modifier, Style};
+use tui_piechart::border_style::BorderStyle;
+use tui_piechart::config::{PaletteConfig, PieChartConfig, PlaceholderConfig, SliceConfig};
+use tui_piechart::{
+    FillPattern, LegendAlignment, LegendLayout, LegendPosition, Resolution, TitleAlignment,
+    TitlePosition, TitleStyle, ValuePolicy,
+};
+
+#[test]
+fn test_enums_use_kebab_case() {
+    assert_eq!(
+        serde_json::to_string(&LegendPosition::Bottom).unwrap(),
+        r#""bottom""#
+    );
+    assert_eq!(
+        serde_json::to_string(&BorderStyle::RoundedCornerGapped).unwrap(),
+        r#""rounded-corner-gapped""#
+    );
+    assert_eq!(
+        serde_json::to_string(&TitleStyle::BoldItalic).unwrap(),
+        r#""bold-italic""#
+    );
+    assert_eq!(
+        serde_json::from_str::<Resolution>(r#""braille""#).unwrap(),
+        Resolution::Braille
+    );
+    assert_eq!(
+        serde_json::from_str::<FillPattern>(r#"{"char":"x"}"#).unwrap(),
+        FillPattern::Char('x')
+    );
+}
+
+#[test]
+fn test_config_round_trip() {
+    let config = PieChartConfig {
+        slices: vec![
+            SliceConfig::new("Rust", 45.0).color(Color::Red),
+            SliceConfig {
+                pattern: Some(FillPattern::CrossHatch),
+                legend_marker: Some("→".to_string()),
+                style: Style::new().add_modifier(Modifier::BOLD),
+                legend_style: Some(Style::new().fg(Color::Yellow)),
+                ..SliceConfig::new("Go", 30.0)
+            },
+        ],
+        resolution: Resolution::Braille,
+        legend_position: LegendPosition::Left,
+        legend_layout: LegendLayout::Horizontal,
+        legend_alignment: LegendAlignment::Right,
+        palette: PaletteConfig::Custom(vec![Color::Rgb(255, 87, 34), Color::Indexed(42)]),
+        highlight_style: Style::new().fg(Color::Black).bg(Color::Yellow),
+        legend_title: Some("Share".to_string()),
+        legend_title_alignment: TitleAlignment::Start,
+        legend_title_position: TitlePosition::Bottom,
+        legend_title_style: Style::new().add_modifier(Modifier::UNDERLINED),
+        placeholder: Some(PlaceholderConfig {
+            text: "No languages".to_string(),
+            ring: Some(Style::new().fg(Color::DarkGray)),
+            ..Default::default()
+        }),
+        border_style: Some(BorderStyle::ThickDashed),
+        title: Some("Languages".to_string()),
+        title_style: TitleStyle::Script,
+        title_alignment: TitleAlignment::End,
+        title_position: TitlePosition::Bottom,
+        ..Default::default()
+    };
+
+    let json = serde_json::to_string(&config).unwrap();
+    let parsed: PieChartConfig = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, config);
+    assert_eq!(parsed.chart(), config.chart());
+}
+
+#[test]
+fn test_config_missing_fields_use_defaults() {
+    let json = r#"{
+        "slices": [
+            { "label": "Rust", "value": 45.0, "color": "red" },
+            { "label": "Go", "value": 30.0 }
+        ],
+        "palette": "okabe-ito",
+        "value_policy": "skip",
+        "border_style": "rounded"
+    }"#;
+    let config: PieChartConfig = serde_json::from_str(json).unwrap();
+
+    assert_eq!(config.slices[0].color, Some(Color::Red));
+    assert_eq!(config.slices[1].color, None);
+    assert_eq!(config.palette, PaletteConfig::OkabeIto);
+    assert_eq!(config.value_policy, ValuePolicy::Skip);
+    assert!(config.show_legend);
+    assert_eq!(config.legend_position, LegendPosition::Right);
+}